@@ -0,0 +1,42 @@
+/// Parse `#[status(key = value, ...)]` attributes into their key/value pairs.
+///
+/// `allowed` lists the keys the caller understands; anything else is an error.
+pub(crate) fn parse(
+    attrs: &[syn::Attribute],
+    allowed: &[&str],
+) -> syn::Result<Vec<(syn::Ident, syn::Lit)>> {
+    let mut pairs = Vec::new();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("status")) {
+        attr.parse_nested_meta(|meta| {
+            let key = meta.path.require_ident()?.clone();
+            if !allowed.iter().any(|a| key == a) {
+                return Err(meta.error(format!(
+                    "unknown `status` attribute, expected one of: {}",
+                    allowed.join(", ")
+                )));
+            }
+            if pairs.iter().any(|(k, _)| *k == key) {
+                return Err(meta.error(format!("duplicate `{}` attribute", key)));
+            }
+            let value: syn::Lit = meta.value()?.parse()?;
+            pairs.push((key, value));
+            Ok(())
+        })?;
+    }
+    Ok(pairs)
+}
+
+/// Look up a string-valued attribute.
+pub(crate) fn string(
+    pairs: &[(syn::Ident, syn::Lit)],
+    key: &str,
+) -> syn::Result<Option<syn::LitStr>> {
+    match pairs.iter().find(|(k, _)| k == key) {
+        Some((_, syn::Lit::Str(s))) => Ok(Some(s.clone())),
+        Some((_, lit)) => Err(syn::Error::new_spanned(
+            lit,
+            format!("`{}` must be a string", key),
+        )),
+        None => Ok(None),
+    }
+}
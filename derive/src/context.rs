@@ -0,0 +1,102 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::attr;
+
+struct Field<'a> {
+    ident: &'a syn::Ident,
+    label: syn::LitStr,
+}
+
+pub(crate) fn derive(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "`#[derive(Context)]` only supports structs with named fields",
+            ))
+        }
+    };
+    let fields = fields.iter().map(field).collect::<syn::Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let idents: Vec<_> = fields.iter().map(|f| f.ident).collect();
+    let labels: Vec<_> = fields.iter().map(|f| &f.label).collect();
+
+    Ok(quote! {
+        impl #impl_generics ::status::Context for #name #ty_generics #where_clause {
+            fn update(mut self, replacements: Self) -> Self {
+                #(
+                    if replacements.#idents.is_some() {
+                        self.#idents = replacements.#idents;
+                    }
+                )*
+                self
+            }
+
+            fn is_empty(&self) -> bool {
+                true #(&& self.#idents.is_none())*
+            }
+
+            fn visit(
+                &self,
+                visitor: &mut dyn ::core::ops::FnMut(&str, &dyn ::core::fmt::Display),
+            ) {
+                #(
+                    if let ::core::option::Option::Some(value) = &self.#idents {
+                        visitor(#labels, value);
+                    }
+                )*
+            }
+        }
+
+        impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#idents: ::core::option::Option::None,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #(
+                    if let ::core::option::Option::Some(value) = &self.#idents {
+                        ::core::writeln!(f, "{}: {}", #labels, value)?;
+                    }
+                )*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+fn field(field: &syn::Field) -> syn::Result<Field<'_>> {
+    let ident = field.ident.as_ref().expect("named fields");
+    if !is_option(&field.ty) {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            "`#[derive(Context)]` fields must be `Option<_>`",
+        ));
+    }
+    let attrs = attr::parse(&field.attrs, &["label"])?;
+    let label = attr::string(&attrs, "label")?
+        .unwrap_or_else(|| syn::LitStr::new(&ident.to_string(), ident.span()));
+    Ok(Field { ident, label })
+}
+
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .map(|s| s.ident == "Option")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
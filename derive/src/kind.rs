@@ -0,0 +1,157 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::attr;
+
+struct Variant<'a> {
+    ident: &'a syn::Ident,
+    code: Code,
+    message: syn::LitStr,
+}
+
+#[derive(PartialEq)]
+enum Code {
+    Int(i64),
+    Str(String),
+}
+
+impl std::fmt::Display for Code {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(code) => write!(f, "{}", code),
+            Self::Str(code) => write!(f, "{:?}", code),
+        }
+    }
+}
+
+pub(crate) fn derive(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let variants = match &input.data {
+        syn::Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "`#[derive(Kind)]` only supports enums",
+            ))
+        }
+    };
+    let variants = variants
+        .iter()
+        .map(variant)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let mut error: Option<syn::Error> = None;
+    for (i, v) in variants.iter().enumerate() {
+        if let Some(first) = variants[..i].iter().find(|f| f.code == v.code) {
+            let e = syn::Error::new_spanned(
+                v.ident,
+                format!("code {} is already used by `{}`", v.code, first.ident),
+            );
+            match &mut error {
+                Some(error) => error.combine(e),
+                None => error = Some(e),
+            }
+        }
+    }
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let idents: Vec<_> = variants.iter().map(|v| v.ident).collect();
+    let codes: Vec<_> = variants
+        .iter()
+        .map(|v| match &v.code {
+            Code::Int(code) => quote!(::status::Code::Int(#code)),
+            Code::Str(code) => quote!(::status::Code::from(#code)),
+        })
+        .collect();
+    let (int_idents, int_codes): (Vec<_>, Vec<&i64>) = variants
+        .iter()
+        .filter_map(|v| match &v.code {
+            Code::Int(code) => Some((v.ident, code)),
+            Code::Str(_) => None,
+        })
+        .unzip();
+    let (str_idents, str_codes): (Vec<_>, Vec<&String>) = variants
+        .iter()
+        .filter_map(|v| match &v.code {
+            Code::Int(_) => None,
+            Code::Str(code) => Some((v.ident, code)),
+        })
+        .unzip();
+    let messages: Vec<_> = variants.iter().map(|v| &v.message).collect();
+
+    Ok(quote! {
+        impl #impl_generics ::status::KindCode for #name #ty_generics #where_clause {
+            fn code(&self) -> ::status::Code {
+                match *self {
+                    #(Self::#idents => #codes,)*
+                }
+            }
+
+            fn from_code(code: ::status::Code) -> ::core::option::Option<Self> {
+                match code {
+                    #(::status::Code::Int(#int_codes) => ::core::option::Option::Some(Self::#int_idents),)*
+                    ::status::Code::Str(code) => match &*code {
+                        #(#str_codes => ::core::option::Option::Some(Self::#str_idents),)*
+                        _ => ::core::option::Option::None,
+                    },
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match *self {
+                    #(Self::#idents => f.write_str(#messages),)*
+                }
+            }
+        }
+    })
+}
+
+fn variant(variant: &syn::Variant) -> syn::Result<Variant<'_>> {
+    if !matches!(variant.fields, syn::Fields::Unit) {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "`#[derive(Kind)]` only supports unit variants",
+        ));
+    }
+    let attrs = attr::parse(&variant.attrs, &["code", "message"])?;
+    let code = match attrs.iter().find(|(k, _)| k == "code") {
+        Some((_, syn::Lit::Int(code))) => Code::Int(code.base10_parse()?),
+        Some((_, syn::Lit::Str(code))) => {
+            // `Code::from_str` would read it back as `Code::Int`.
+            if code.value().parse::<i64>().is_ok() {
+                return Err(syn::Error::new_spanned(
+                    code,
+                    "string `code` must not be an integer, use an integer `code` instead",
+                ));
+            }
+            Code::Str(code.value())
+        }
+        Some((_, lit)) => {
+            return Err(syn::Error::new_spanned(
+                lit,
+                "`code` must be an integer or string",
+            ))
+        }
+        None => {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "missing `#[status(code = ...)]`",
+            ))
+        }
+    };
+    let message = attr::string(&attrs, "message")?.ok_or_else(|| {
+        syn::Error::new_spanned(variant, "missing `#[status(message = \"...\")]`")
+    })?;
+    Ok(Variant {
+        ident: &variant.ident,
+        code,
+        message,
+    })
+}
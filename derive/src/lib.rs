@@ -0,0 +1,101 @@
+//! Derive macros for [`status`](https://docs.rs/status).
+//!
+//! These are re-exported by `status` when its `derive` feature is enabled.
+
+#![warn(missing_docs)]
+
+extern crate proc_macro;
+
+mod attr;
+mod context;
+mod kind;
+
+use proc_macro::TokenStream;
+
+/// Implement `status::Context` for a struct of `Option` fields.
+///
+/// This generates:
+/// - `Context::update`, replacing each field populated in the replacements.
+/// - `Context::is_empty`, checking that every field is `None`.
+/// - `Context::visit`, visiting each populated field by its label.
+/// - `Default`, with every field `None`.
+/// - `Display`, with a `label: value` line for each populated field.
+///
+/// Labels default to the field's name and can be set with `#[status(label = "...")]`.
+///
+/// # Example
+///
+/// ```rust
+/// #[derive(Clone, Debug, status::Context)]
+/// struct Context {
+///     #[status(label = "Expected value")]
+///     expected: Option<usize>,
+///     path: Option<String>,
+/// }
+///
+/// let context = Context {
+///     expected: Some(5),
+///     ..Default::default()
+/// };
+/// assert_eq!(context.to_string(), "Expected value: 5\n");
+/// ```
+#[proc_macro_derive(Context, attributes(status))]
+pub fn derive_context(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    context::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implement `Display` and `status::KindCode` for a `status::Kind` `enum`.
+///
+/// Each variant must have a `#[status(code = ..., message = "...")]` attribute:
+/// - `code`: an integer or namespaced string, unique within the `enum`.  A string `code` must not
+///   be an integer, so it round-trips through `Code::from_str`.
+/// - `message`: the `Display` for the variant.
+///
+/// # Example
+///
+/// ```rust
+/// use status::Code;
+/// use status::KindCode;
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, status::Kind)]
+/// enum ErrorKind {
+///     #[status(code = 1042, message = "Failed to read file")]
+///     Read,
+///     #[status(code = "parse.E1043", message = "Failed to parse")]
+///     Parse,
+/// }
+///
+/// assert_eq!(ErrorKind::Read.code(), Code::Int(1042));
+/// assert_eq!(ErrorKind::from_code(Code::from("parse.E1043")), Some(ErrorKind::Parse));
+/// assert_eq!(ErrorKind::Read.to_string(), "Failed to read file");
+/// ```
+///
+/// Codes must be unique:
+///
+/// ```rust,compile_fail
+/// #[derive(Copy, Clone, Debug, status::Kind)]
+/// enum ErrorKind {
+///     #[status(code = 1042, message = "Failed to read file")]
+///     Read,
+///     #[status(code = 1042, message = "Failed to parse")]
+///     Parse,
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[derive(Copy, Clone, Debug, status::Kind)]
+/// enum ErrorKind {
+///     #[status(code = "1042", message = "Failed to read file")]
+///     Read,
+/// }
+/// ```
+#[proc_macro_derive(Kind, attributes(status))]
+pub fn derive_kind(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    kind::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,93 @@
+use status::Context as _;
+
+#[derive(Clone, Debug, status::Context)]
+struct Context {
+    #[status(label = "Expected value")]
+    expected: Option<usize>,
+    path: Option<String>,
+}
+
+#[test]
+fn is_empty() {
+    assert!(Context::default().is_empty());
+
+    let c = Context {
+        path: Some("Cargo.toml".to_owned()),
+        ..Default::default()
+    };
+    assert!(!c.is_empty());
+}
+
+#[test]
+fn update_only_populated() {
+    let c = Context {
+        expected: Some(5),
+        path: Some("Cargo.toml".to_owned()),
+    }
+    .update(Context {
+        expected: Some(10),
+        ..Default::default()
+    });
+    assert_eq!(c.expected, Some(10));
+    assert_eq!(c.path.as_deref(), Some("Cargo.toml"));
+}
+
+#[test]
+fn display() {
+    let c = Context {
+        expected: Some(5),
+        path: Some("Cargo.toml".to_owned()),
+    };
+    assert_eq!(c.to_string(), "Expected value: 5\npath: Cargo.toml\n");
+
+    let c = Context {
+        path: Some("Cargo.toml".to_owned()),
+        ..Default::default()
+    };
+    assert_eq!(c.to_string(), "path: Cargo.toml\n");
+}
+
+#[test]
+fn visit() {
+    let c = Context {
+        expected: Some(5),
+        ..Default::default()
+    };
+    let mut fields = Vec::new();
+    c.visit(&mut |label, value| fields.push((label.to_owned(), value.to_string())));
+    assert_eq!(fields, [("Expected value".to_owned(), "5".to_owned())]);
+}
+
+#[test]
+fn status() {
+    let status = status::Status::<status::Unkind, Context>::new("Failed to read file")
+        .context_with(|c| {
+            c.update(Context {
+                expected: Some(5),
+                ..Default::default()
+            })
+        });
+    assert_eq!(status.expected, Some(5));
+}
+
+#[test]
+fn secret() {
+    #[derive(Clone, Debug, status::Context)]
+    struct Login {
+        token: Option<status::Secret<String>>,
+    }
+
+    let c = Login {
+        token: Some(status::Secret::new("hunter2".to_owned())),
+    };
+    assert_eq!(c.to_string(), "token: [redacted]\n");
+    assert_eq!(format!("{:#}", c), "token: [redacted]\n");
+
+    let status = status::Status::<status::Unkind, Login>::new("Failed to authenticate")
+        .context_with(|_| c.clone());
+    assert!(!status.to_string().contains("hunter2"));
+    assert!(status
+        .into_internal()
+        .to_string()
+        .contains("token: hunter2"));
+}
@@ -0,0 +1,53 @@
+use status::Code;
+use status::KindCode;
+
+#[derive(Copy, Clone, Debug, PartialEq, status::Kind)]
+enum ErrorKind {
+    #[status(code = 1042, message = "Failed to read file")]
+    Read,
+    #[status(code = "parse.E1043", message = "Failed to parse")]
+    Parse,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, status::Kind)]
+enum IntKind {
+    #[status(code = 1, message = "One")]
+    One,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, status::Kind)]
+enum EmptyKind {}
+
+#[test]
+fn code() {
+    assert_eq!(ErrorKind::Read.code(), Code::Int(1042));
+    assert_eq!(ErrorKind::Parse.code(), Code::from("parse.E1043"));
+    assert_eq!(IntKind::One.code(), Code::Int(1));
+}
+
+#[test]
+fn from_code() {
+    assert_eq!(ErrorKind::from_code(Code::Int(1042)), Some(ErrorKind::Read));
+    assert_eq!(
+        ErrorKind::from_code("parse.E1043".parse().unwrap()),
+        Some(ErrorKind::Parse)
+    );
+    assert_eq!(ErrorKind::from_code(Code::Int(1043)), None);
+    assert_eq!(IntKind::from_code(Code::Int(1)), Some(IntKind::One));
+    assert_eq!(IntKind::from_code(Code::from("1")), None);
+    assert_eq!(EmptyKind::from_code(Code::Int(1)), None);
+}
+
+#[test]
+fn display() {
+    assert_eq!(ErrorKind::Read.to_string(), "Failed to read file");
+    assert_eq!(ErrorKind::Parse.to_string(), "Failed to parse");
+}
+
+#[test]
+fn status() {
+    let status = status::Status::<ErrorKind>::new(ErrorKind::Parse);
+    assert_eq!(status.kind(), ErrorKind::Parse);
+    assert_eq!(status.code(), Code::from("parse.E1043"));
+    assert_eq!(status.to_string(), "Failed to parse\n");
+}
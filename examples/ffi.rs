@@ -0,0 +1,51 @@
+//! Library exposing a `status_t` to C, for `ffi/test.c`.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use status::ffi::status_t;
+
+#[derive(Copy, Clone, Debug, PartialEq, derive_more::Display)]
+enum ErrorKind {
+    #[display(fmt = "Failed to read file")]
+    Read,
+}
+
+impl status::KindCode for ErrorKind {
+    fn code(&self) -> status::Code {
+        match self {
+            Self::Read => status::Code::Int(1042),
+        }
+    }
+
+    fn from_code(code: status::Code) -> Option<Self> {
+        match code.as_int()? {
+            1042 => Some(Self::Read),
+            _ => None,
+        }
+    }
+}
+
+type Status = status::Status<ErrorKind>;
+
+fn read_file(path: &str) -> Result<String, Status> {
+    std::fs::read_to_string(path).map_err(|e| {
+        Status::new(ErrorKind::Read)
+            .with_source(e)
+            .context_with(|c| c.insert("Path", path.to_owned()))
+    })
+}
+
+/// Read `path`, returning NULL on success.
+///
+/// # Safety
+///
+/// `path` must be a valid C string.
+#[no_mangle]
+pub unsafe extern "C" fn example_read_file(path: *const c_char) -> *mut status_t {
+    let path = CStr::from_ptr(path).to_string_lossy();
+    match read_file(&path) {
+        Ok(_) => std::ptr::null_mut(),
+        Err(status) => status::ffi::into_raw(status),
+    }
+}
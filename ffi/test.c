@@ -0,0 +1,68 @@
+/* Exercises include/status.h against examples/ffi.rs; run with `make -C ffi test`. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "status.h"
+
+status_t *example_read_file(const char *path);
+
+static int failures = 0;
+
+#define CHECK(cond)                                                   \
+    do {                                                              \
+        if (!(cond)) {                                                \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,    \
+                    __LINE__, #cond);                                 \
+            failures += 1;                                            \
+        }                                                             \
+    } while (0)
+
+static void test_success(void) {
+    status_t *status = example_read_file("../Cargo.toml");
+    CHECK(status == NULL);
+    status_free(status);
+}
+
+static void test_failure(void) {
+    status_t *status = example_read_file("missing.txt");
+    CHECK(status != NULL);
+
+    int64_t code = 0;
+    CHECK(status_code(status, &code));
+    CHECK(code == 1042);
+    CHECK(strcmp(status_code_str(status), "1042") == 0);
+    CHECK(strcmp(status_kind(status), "Failed to read file") == 0);
+    CHECK(strstr(status_message(status), "Path: missing.txt") != NULL);
+
+    CHECK(status_context_len(status) == 1);
+    CHECK(strcmp(status_context_key(status, 0), "Path") == 0);
+    CHECK(strcmp(status_context_value(status, 0), "missing.txt") == 0);
+    CHECK(status_context_key(status, 1) == NULL);
+
+    CHECK(status_sources_len(status) == 1);
+    CHECK(status_source(status, 0) != NULL);
+    CHECK(status_source(status, 1) == NULL);
+
+    status_free(status);
+}
+
+static void test_null(void) {
+    int64_t code = 0;
+    CHECK(!status_code(NULL, &code));
+    CHECK(status_kind(NULL) == NULL);
+    CHECK(status_context_len(NULL) == 0);
+    status_free(NULL);
+}
+
+int main(void) {
+    test_success();
+    test_failure();
+    test_null();
+    if (failures != 0) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    printf("ok\n");
+    return 0;
+}
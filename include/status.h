@@ -0,0 +1,99 @@
+#ifndef STATUS_H
+#define STATUS_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// Opaque snapshot of a [`Status`] for C.
+//
+// Only the public sources are included.
+typedef struct status_t status_t;
+
+// Release a `status_t`.
+//
+// # Safety
+//
+// `status` must be NULL or come from [`into_raw`], and must not be used afterwards.
+void status_free(struct status_t *status);
+
+// The integer code of the status' kind.
+//
+// Returns `false` if the code is not an integer or `status` is NULL.
+//
+// # Safety
+//
+// `status` must be NULL or a live `status_t` and `code` must be valid for writes.
+bool status_code(const struct status_t *status, int64_t *code);
+
+// The code of the status' kind, rendered as a string.
+//
+// Returns NULL if `status` is NULL.
+//
+// # Safety
+//
+// `status` must be NULL or a live `status_t`.
+const char *status_code_str(const struct status_t *status);
+
+// The message for the status' kind.
+//
+// Returns NULL if `status` is NULL.
+//
+// # Safety
+//
+// `status` must be NULL or a live `status_t`.
+const char *status_kind(const struct status_t *status);
+
+// The status rendered for the user, including its context.
+//
+// Returns NULL if `status` is NULL.
+//
+// # Safety
+//
+// `status` must be NULL or a live `status_t`.
+const char *status_message(const struct status_t *status);
+
+// The number of context entries.
+//
+// # Safety
+//
+// `status` must be NULL or a live `status_t`.
+size_t status_context_len(const struct status_t *status);
+
+// The key of the context entry at `index`.
+//
+// Returns NULL if `index` is out of bounds.
+//
+// # Safety
+//
+// `status` must be NULL or a live `status_t`.
+const char *status_context_key(const struct status_t *status, size_t index);
+
+// The value of the context entry at `index`.
+//
+// Returns NULL if `index` is out of bounds.
+//
+// # Safety
+//
+// `status` must be NULL or a live `status_t`.
+const char *status_context_value(const struct status_t *status, size_t index);
+
+// The number of public sources in the chain.
+//
+// # Safety
+//
+// `status` must be NULL or a live `status_t`.
+size_t status_sources_len(const struct status_t *status);
+
+// The message of the source at `index`, starting from the immediate source.
+//
+// Returns NULL if `index` is out of bounds.
+//
+// # Safety
+//
+// `status` must be NULL or a live `status_t`.
+const char *status_source(const struct status_t *status, size_t index);
+
+#endif  /* STATUS_H */
@@ -1,4 +1,17 @@
-type StdError = dyn std::error::Error + 'static;
+use alloc::string::String;
+use alloc::string::ToString;
+use core::fmt;
+use core::iter;
+use core::marker::PhantomData;
+use core::slice;
+
+use crate::Context;
+use crate::Kind;
+use crate::Report;
+use crate::Source;
+use crate::Status;
+
+type StdError = dyn core::error::Error + 'static;
 
 /// Iterator of a chain of source errors.
 ///
@@ -12,22 +25,42 @@ type StdError = dyn std::error::Error + 'static;
 /// use std::io;
 ///
 /// pub fn underlying_io_error_kind(error: &Status) -> Option<io::ErrorKind> {
-///     for cause in error.sources() {
-///         if let Some(io_error) = cause.downcast_ref::<io::Error>() {
-///             return Some(io_error.kind());
-///         }
-///     }
-///     None
+///     error.sources().downcast::<io::Error>().next().map(|e| e.kind())
 /// }
 /// ```
 #[derive(Debug)]
 pub struct Chain<'a> {
     next: Option<&'a StdError>,
+    step: fn(&'a StdError) -> Option<&'a StdError>,
 }
 
 impl<'a> Chain<'a> {
     pub(crate) fn new(next: Option<&'a StdError>) -> Self {
-        Self { next }
+        Self {
+            next,
+            step: |e| e.source(),
+        }
+    }
+
+    /// Like [`Chain::new`] but continuing through the private sources of nested `Status`es.
+    ///
+    /// Without `std`, only nested `Status<K, C>`s are continued through.
+    pub(crate) fn internal<K: Kind, C: Context>(next: Option<&'a StdError>) -> Self {
+        Self {
+            next,
+            step: internal_source::<K, C>,
+        }
+    }
+
+    /// Only the errors of type `T`.
+    ///
+    /// To find a nested [`Status`][crate::Status], `T` must be its exact type, including the
+    /// [`Context`][crate::Context].
+    pub fn downcast<T: core::error::Error + 'static>(self) -> Downcast<'a, T> {
+        Downcast {
+            chain: self,
+            _type: PhantomData,
+        }
     }
 }
 
@@ -36,11 +69,143 @@ impl<'a> Iterator for Chain<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let next = self.next.take()?;
-        self.next = next.source();
+        self.next = (self.step)(next);
         Some(next)
     }
 }
 
+fn internal_source<K: Kind, C: Context>(error: &StdError) -> Option<&StdError> {
+    if let Some(source) = status_source::<K, C>(error) {
+        return source;
+    }
+    #[cfg(feature = "std")]
+    {
+        let sources = STATUS_SOURCES.read().unwrap_or_else(|e| e.into_inner());
+        if let Some(source) = sources.iter().find_map(|(_, source)| source(error)) {
+            return source;
+        }
+    }
+    error.source()
+}
+
+/// The private or public source of `error`, if it is a `Status<K, C>`.
+fn status_source<K: Kind, C: Context>(error: &StdError) -> Option<Option<&StdError>> {
+    error
+        .downcast_ref::<Status<K, C>>()
+        .map(Report::internal_source)
+}
+
+#[cfg(feature = "std")]
+type StatusSource = for<'a> fn(&'a StdError) -> Option<Option<&'a StdError>>;
+
+/// [`status_source`] for each `Status` type given a private source, so [`Chain::internal`] can
+/// step through nested `Status`es of any [`Kind`] and [`Context`].
+#[cfg(feature = "std")]
+static STATUS_SOURCES: std::sync::RwLock<Vec<(core::any::TypeId, StatusSource)>> =
+    std::sync::RwLock::new(Vec::new());
+
+/// Let [`Chain::internal`] step into the private source of a nested `Status<K, C>`.
+pub(crate) fn register_status<K: Kind, C: Context>() {
+    #[cfg(feature = "std")]
+    {
+        let id = core::any::TypeId::of::<Status<K, C>>();
+        let registered = |sources: &[(core::any::TypeId, StatusSource)]| {
+            sources.iter().any(|(registered, _)| *registered == id)
+        };
+        if registered(&STATUS_SOURCES.read().unwrap_or_else(|e| e.into_inner())) {
+            return;
+        }
+        let mut sources = STATUS_SOURCES.write().unwrap_or_else(|e| e.into_inner());
+        if !registered(&sources) {
+            sources.push((id, status_source::<K, C>));
+        }
+    }
+}
+
+/// Iterator of the errors of type `T` in a [`Chain`].
+///
+/// See [`Chain::downcast`].
+#[derive(Debug)]
+pub struct Downcast<'a, T> {
+    chain: Chain<'a>,
+    _type: PhantomData<&'a T>,
+}
+
+impl<'a, T: core::error::Error + 'static> Iterator for Downcast<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chain.by_ref().find_map(|e| e.downcast_ref::<T>())
+    }
+}
+
+/// Iterator of errors related to a [`Status`][crate::Status].
+///
+/// [`Status::related`][crate::Status::related] will only return errors that are part of the API /
+/// user-visible. To access debug / internal information, see
+/// [`InternalStatus::related`][crate::InternalStatus::related].
+#[derive(Debug)]
+pub struct Related<'a> {
+    iter: slice::Iter<'a, Source>,
+    internal: bool,
+}
+
+impl<'a> Related<'a> {
+    pub(crate) fn new(related: &'a [Source], internal: bool) -> Self {
+        Self {
+            iter: related.iter(),
+            internal,
+        }
+    }
+}
+
+impl<'a> Iterator for Related<'a> {
+    type Item = &'a StdError;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let internal = self.internal;
+        self.iter.by_ref().find_map(|s| s.get(internal))
+    }
+}
+
+/// Render each error, and its chain of sources, as a tree.
+pub(crate) fn render_tree<'a>(
+    f: &mut fmt::Formatter<'_>,
+    errors: impl Iterator<Item = &'a StdError>,
+) -> fmt::Result {
+    let mut errors = errors.peekable();
+    while let Some(error) = errors.next() {
+        render_node(f, "", error, errors.peek().is_none())?;
+    }
+    Ok(())
+}
+
+fn render_node(
+    f: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    error: &StdError,
+    last: bool,
+) -> fmt::Result {
+    let (branch, indent) = if last {
+        ("└─ ", "   ")
+    } else {
+        ("├─ ", "│  ")
+    };
+    let child_prefix = String::from(prefix) + indent;
+
+    let message = error.to_string();
+    let mut lines = message.trim_end().lines();
+    let first = iter::once((String::from(prefix) + branch, lines.next().unwrap_or("")));
+    for (prefix, line) in first.chain(lines.map(|l| (child_prefix.clone(), l))) {
+        writeln!(f, "{}", (prefix + line).trim_end())?;
+    }
+
+    match error.source() {
+        Some(source) => render_node(f, &child_prefix, source, true),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -49,6 +214,43 @@ mod test {
 
     #[test]
     fn chain() {
-        assert_impl_all!(Chain: std::fmt::Debug);
+        assert_impl_all!(Chain: core::fmt::Debug);
+    }
+
+    #[test]
+    fn downcast() {
+        use crate::NoContext;
+        use crate::Status;
+        use std::io;
+
+        #[derive(Copy, Clone, Debug, PartialEq, derive_more::Display)]
+        enum IoKind {
+            #[display(fmt = "Failed to read file")]
+            Read,
+        }
+
+        let status = <Status>::new("Failed to sync").with_source(
+            <Status>::new("Failed to load config").with_source(
+                Status::<IoKind, NoContext>::new(IoKind::Read)
+                    .with_source(io::Error::new(io::ErrorKind::TimedOut, "Timed out")),
+            ),
+        );
+        assert_eq!(status.sources().downcast::<Status>().count(), 1);
+        assert_eq!(
+            status
+                .find_source::<Status<IoKind, NoContext>>()
+                .map(|s| s.kind()),
+            Some(IoKind::Read)
+        );
+        assert_eq!(
+            status.find_source::<io::Error>().map(|e| e.kind()),
+            Some(io::ErrorKind::TimedOut)
+        );
+        assert!(status.find_source::<Status<IoKind>>().is_none());
+
+        let status = <Status>::new("Failed to sync")
+            .with_internal(io::Error::new(io::ErrorKind::TimedOut, "Timed out"));
+        assert!(status.find_source::<io::Error>().is_none());
+        assert!(status.into_internal().find_source::<io::Error>().is_some());
     }
 }
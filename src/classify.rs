@@ -0,0 +1,180 @@
+use core::error;
+use core::fmt;
+
+use crate::Context;
+use crate::Kind;
+use crate::Status;
+
+/// Decide the [`Kind`] for a foreign error, so `?` can convert it to a [`Status`].
+///
+/// Without specialization, there can't be an `impl From<E: Error> for Status`.  Instead, mark the
+/// error with [`ResultClassifyExt::classify`] and `?` will convert it, keeping the error as an
+/// internal source.
+///
+/// An error can be classified for several [`Kind`]s, like one per layer of your application.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io;
+/// use status::Classify;
+/// use status::ResultClassifyExt;
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, derive_more::Display)]
+/// enum ErrorKind {
+///   #[display(fmt = "File is missing")]
+///   Missing,
+///   #[display(fmt = "Failed to read file")]
+///   Read,
+/// }
+///
+/// impl Classify<ErrorKind> for io::Error {
+///     fn classify(&self) -> ErrorKind {
+///         match self.kind() {
+///             io::ErrorKind::NotFound => ErrorKind::Missing,
+///             _ => ErrorKind::Read,
+///         }
+///     }
+/// }
+///
+/// fn read_file(path: &str) -> Result<String, status::Status<ErrorKind>> {
+///     let content = std::fs::read_to_string(path).classify()?;
+///     Ok(content)
+/// }
+///
+/// let status = read_file("does-not-exist.toml").unwrap_err();
+/// assert_eq!(status.kind(), ErrorKind::Missing);
+/// ```
+pub trait Classify<K: Kind>: error::Error {
+    /// The [`Kind`] of [`Status`] for this error.
+    fn classify(&self) -> K;
+}
+
+/// An error waiting for `?` to convert it to a [`Status`], see [`Classify`].
+#[derive(Debug)]
+pub struct Unclassified<E>(E);
+
+impl<E> Unclassified<E> {
+    /// Mark `error` for classification.
+    pub fn new(error: E) -> Self {
+        Self(error)
+    }
+
+    /// Access the error.
+    pub fn get_ref(&self) -> &E {
+        &self.0
+    }
+
+    /// Take the error, without classifying it.
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Unclassified<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "send_sync")]
+impl<K, C, E> From<Unclassified<E>> for Status<K, C>
+where
+    K: Kind,
+    C: Context,
+    E: Classify<K> + Send + Sync + 'static,
+{
+    fn from(error: Unclassified<E>) -> Self {
+        Self::new(error.0.classify()).with_internal(error.0)
+    }
+}
+#[cfg(not(feature = "send_sync"))]
+impl<K, C, E> From<Unclassified<E>> for Status<K, C>
+where
+    K: Kind,
+    C: Context,
+    E: Classify<K> + 'static,
+{
+    fn from(error: Unclassified<E>) -> Self {
+        Self::new(error.0.classify()).with_internal(error.0)
+    }
+}
+
+/// Mark errors for classification, see [`Classify`].
+pub trait ResultClassifyExt<T, E> {
+    /// Mark the error so `?` converts it to a [`Status`] with the [`Classify`]d [`Kind`].
+    fn classify(self) -> Result<T, Unclassified<E>>;
+}
+
+impl<T, E: error::Error> ResultClassifyExt<T, E> for Result<T, E> {
+    fn classify(self) -> Result<T, Unclassified<E>> {
+        self.map_err(Unclassified::new)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io;
+
+    use crate::NoContext;
+
+    #[derive(Copy, Clone, Debug, PartialEq, derive_more::Display)]
+    enum StorageKind {
+        #[display(fmt = "File is missing")]
+        Missing,
+        #[display(fmt = "Failed to access storage")]
+        Other,
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, derive_more::Display)]
+    enum NetworkKind {
+        #[display(fmt = "Connection timed out")]
+        Timeout,
+        #[display(fmt = "Network failed")]
+        Other,
+    }
+
+    impl Classify<StorageKind> for io::Error {
+        fn classify(&self) -> StorageKind {
+            match self.kind() {
+                io::ErrorKind::NotFound => StorageKind::Missing,
+                _ => StorageKind::Other,
+            }
+        }
+    }
+
+    impl Classify<NetworkKind> for io::Error {
+        fn classify(&self) -> NetworkKind {
+            match self.kind() {
+                io::ErrorKind::TimedOut => NetworkKind::Timeout,
+                _ => NetworkKind::Other,
+            }
+        }
+    }
+
+    fn fail(kind: io::ErrorKind) -> Result<(), io::Error> {
+        Err(io::Error::new(kind, "Failed"))
+    }
+
+    #[test]
+    fn infer_kind_from_return() {
+        fn load() -> Result<(), Status<StorageKind>> {
+            fail(io::ErrorKind::NotFound).classify()?;
+            Ok(())
+        }
+
+        fn fetch() -> Result<(), Status<NetworkKind, NoContext>> {
+            fail(io::ErrorKind::TimedOut).classify()?;
+            Ok(())
+        }
+
+        let status = load().unwrap_err();
+        assert_eq!(status.kind(), StorageKind::Missing);
+        assert!(status.find_source::<io::Error>().is_none());
+        assert!(status.into_internal().find_source::<io::Error>().is_some());
+
+        assert_eq!(fetch().unwrap_err().kind(), NetworkKind::Timeout);
+    }
+}
@@ -0,0 +1,152 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt;
+use core::str;
+
+use crate::Kind;
+
+/// Stable identifier for a [`Kind`], for when the Rust type isn't available, like FFI, IPC, logs,
+/// and localization.
+///
+/// # Example
+///
+/// ```rust
+/// let code: status::Code = "io.read.E1042".parse().unwrap();
+/// assert_eq!(code.namespace(), Some("io.read"));
+/// assert_eq!(code.to_string(), "io.read.E1042");
+///
+/// let code: status::Code = "1042".parse().unwrap();
+/// assert_eq!(code, status::Code::Int(1042));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Code {
+    /// Integer code, like `errno` or `HRESULT`.
+    Int(i64),
+    /// Namespaced string code, like `"io.read.E1042"`.
+    Str(Cow<'static, str>),
+}
+
+impl Code {
+    /// The integer code, if any.
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Int(code) => Some(*code),
+            Self::Str(_) => None,
+        }
+    }
+
+    /// The string code, if any.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Int(_) => None,
+            Self::Str(code) => Some(code),
+        }
+    }
+
+    /// The namespace of a string code, everything before the last `.`.
+    pub fn namespace(&self) -> Option<&str> {
+        self.as_str()
+            .and_then(|code| code.rfind('.').map(|i| &code[..i]))
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(code) => write!(f, "{}", code),
+            Self::Str(code) => write!(f, "{}", code),
+        }
+    }
+}
+
+/// Integers parse as [`Code::Int`], anything else as [`Code::Str`].
+impl str::FromStr for Code {
+    type Err = core::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s
+            .parse()
+            .map(Self::Int)
+            .unwrap_or_else(|_| Self::Str(Cow::Owned(s.into())));
+        Ok(code)
+    }
+}
+
+impl From<i64> for Code {
+    fn from(code: i64) -> Self {
+        Self::Int(code)
+    }
+}
+
+impl From<&'static str> for Code {
+    fn from(code: &'static str) -> Self {
+        Self::Str(Cow::Borrowed(code))
+    }
+}
+
+impl From<String> for Code {
+    fn from(code: String) -> Self {
+        Self::Str(Cow::Owned(code))
+    }
+}
+
+/// Map a [`Kind`] to and from a stable [`Code`].
+///
+/// With the `derive` feature, `#[derive(status::Kind)]` implements this for you.
+///
+/// # Example
+///
+/// ```rust
+/// use status::Code;
+/// use status::KindCode;
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, derive_more::Display)]
+/// enum ErrorKind {
+///   #[display(fmt = "Failed to read file")]
+///   Read,
+/// }
+///
+/// impl KindCode for ErrorKind {
+///     fn code(&self) -> Code {
+///         match self {
+///             Self::Read => Code::from("io.read.E1042"),
+///         }
+///     }
+///
+///     fn from_code(code: Code) -> Option<Self> {
+///         match code.as_str()? {
+///             "io.read.E1042" => Some(Self::Read),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let status = status::Status::<ErrorKind>::new(ErrorKind::Read);
+/// assert_eq!(status.code(), Code::from("io.read.E1042"));
+/// assert_eq!(ErrorKind::from_code(status.code()), Some(ErrorKind::Read));
+/// ```
+pub trait KindCode: Kind {
+    /// The stable code for this [`Kind`].
+    fn code(&self) -> Code;
+
+    /// Look up the [`Kind`] for a `code`, if it is known.
+    fn from_code(code: Code) -> Option<Self>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!("-5".parse::<Code>().unwrap(), Code::Int(-5));
+        assert_eq!("E5".parse::<Code>().unwrap(), Code::from("E5"));
+    }
+
+    #[test]
+    fn namespace() {
+        assert_eq!(Code::from("io.read.E1042").namespace(), Some("io.read"));
+        assert_eq!(Code::from("E1042").namespace(), None);
+        assert_eq!(Code::Int(1042).namespace(), None);
+    }
+}
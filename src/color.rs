@@ -0,0 +1,163 @@
+use std::env;
+use std::ffi::OsStr;
+use std::fmt;
+use std::io::IsTerminal;
+
+use anstyle::AnsiColor;
+use anstyle::Style;
+
+use crate::render::Role;
+use crate::Format;
+use crate::Report;
+use crate::TextRenderer;
+
+const KIND: Style = Style::new()
+    .bold()
+    .fg_color(Some(anstyle::Color::Ansi(AnsiColor::Red)));
+const KEY: Style = Style::new().dimmed();
+const HEADING: Style = Style::new().bold();
+
+/// When to style terminal output.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Style if the stream is a terminal, honoring `NO_COLOR` and `CLICOLOR_FORCE`.
+    #[default]
+    Auto,
+    /// Always style.
+    Always,
+    /// Never style.
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether to style output written to `stream`.
+    pub fn resolve(self, stream: &impl IsTerminal) -> bool {
+        match self {
+            Self::Auto => auto(
+                env::var_os("NO_COLOR").as_deref(),
+                env::var_os("CLICOLOR_FORCE").as_deref(),
+                stream.is_terminal(),
+            ),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+fn auto(no_color: Option<&OsStr>, clicolor_force: Option<&OsStr>, is_terminal: bool) -> bool {
+    if no_color.map(|v| !v.is_empty()).unwrap_or(false) {
+        false
+    } else if clicolor_force.map(|v| v != "0").unwrap_or(false) {
+        true
+    } else {
+        is_terminal
+    }
+}
+
+/// Render a [`Report`] for the terminal, with the kind in bold red and context labels dimmed.
+///
+/// This is [`Format::Plain`] from [`TextRenderer`], which [`TextRenderer::from_env`] styles when
+/// `stderr` is a terminal.
+///
+/// # Example
+///
+/// ```rust
+/// use status::ColorChoice;
+///
+/// let status = <status::Status>::new("Failed to read file")
+///     .context_with(|c| c.insert("Path", "Cargo.toml"));
+/// let color = ColorChoice::Auto.resolve(&std::io::stderr());
+/// eprintln!("{}", status::Styled::new(&status).with_color(color));
+/// ```
+#[derive(Debug)]
+pub struct Styled<'a, E: Report + ?Sized> {
+    error: &'a E,
+    color: bool,
+}
+
+impl<'a, E: Report + ?Sized> Styled<'a, E> {
+    /// Render `error` without styling.
+    pub fn new(error: &'a E) -> Self {
+        Self {
+            error,
+            color: false,
+        }
+    }
+
+    /// Whether to style the output.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl<E: Report + ?Sized> fmt::Display for Styled<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        TextRenderer::new(Format::Plain)
+            .with_color(self.color)
+            .write(f, self.error)
+    }
+}
+
+pub(crate) fn style(role: Role) -> Style {
+    match role {
+        Role::Kind => KIND,
+        Role::Key => KEY,
+        Role::Heading => HEADING,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io;
+
+    type Status = crate::Status;
+
+    fn status() -> Status {
+        Status::new("Failed to read file")
+            .with_source(io::Error::new(io::ErrorKind::NotFound, "missing\nfile"))
+            .context_with(|c| c.insert("Path", "Cargo.toml"))
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(
+            Styled::new(&status()).to_string(),
+            "Failed to read file
+
+Path: Cargo.toml
+
+Caused by: missing
+file
+"
+        );
+    }
+
+    #[test]
+    fn color() {
+        assert_eq!(
+            Styled::new(&status()).with_color(true).to_string(),
+            "\u{1b}[1m\u{1b}[31mFailed to read file\u{1b}[0m
+
+\u{1b}[2mPath:\u{1b}[0m Cargo.toml
+
+\u{1b}[1mCaused by:\u{1b}[0m
+  missing
+  file
+"
+        );
+    }
+
+    #[test]
+    fn auto_env() {
+        assert!(auto(None, None, true));
+        assert!(!auto(None, None, false));
+        assert!(!auto(Some(OsStr::new("1")), None, true));
+        assert!(auto(Some(OsStr::new("")), None, true));
+        assert!(auto(None, Some(OsStr::new("1")), false));
+        assert!(!auto(None, Some(OsStr::new("0")), false));
+        assert!(!auto(Some(OsStr::new("1")), Some(OsStr::new("1")), true));
+    }
+}
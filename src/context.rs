@@ -1,4 +1,12 @@
-use std::fmt;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::Any;
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use core::fmt;
+use core::marker::PhantomData;
 
 /// Adds nuance to errors.
 ///
@@ -12,10 +20,18 @@ pub trait Context: Default + Clone + fmt::Display + fmt::Debug + Send + Sync + '
 
     /// Returns `true` is the `Context` has no content.
     fn is_empty(&self) -> bool;
+
+    /// Visit each populated field as a label and value, for structured rendering.
+    ///
+    /// By default, nothing is visited and renderers fall back to `Display`.
+    fn visit(&self, visitor: &mut dyn FnMut(&str, &dyn fmt::Display)) {
+        let _ = visitor;
+    }
 }
 
 /// No context needed.
 #[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NoContext;
 
 impl fmt::Display for NoContext {
@@ -37,12 +53,15 @@ impl Context for NoContext {
 /// Adhoc [`Context`].
 ///
 /// Unlike most [`Context`]s, this is meant to be opaque and not programmatically specify the status.
-/// It is only good for displaying the data to the user when prototyping before one transitions to more formal [`Context`]s.
+/// It is mostly for displaying the data to the user when prototyping before one transitions to more formal [`Context`]s.
+/// Values can still be read back with [`AdhocContext::get`], and a [`Key`] checks their type
+/// at compile time.
 ///
 /// Note: This is the default [`Context`] for [`Status`].
 #[derive(Default, Clone, Debug)]
 pub struct AdhocContext {
-    data: indexmap::IndexMap<&'static str, Box<dyn AdhocValue>>,
+    // Contexts are small and need to preserve insertion order, so a linear scan is fine.
+    pub(crate) data: Vec<(Cow<'static, str>, Box<dyn AdhocValue>)>,
 }
 
 impl AdhocContext {
@@ -58,24 +77,83 @@ impl AdhocContext {
     ///
     /// If no equivalent key existed: the new key-value pair is inserted, last in order.
     ///
+    /// Keys may be `&'static str`, a [`Key`], or owned for when they are only known at runtime.
+    ///
     /// # Example
     ///
     /// ```rust
-    /// let c = status::AdhocContext::new().insert("Expected value", 10);
+    /// let field = String::from("timeout");
+    /// let c = status::AdhocContext::new()
+    ///     .insert("Expected value", 10)
+    ///     .insert(field, "30s");
     /// println!("{}", c);
     /// ```
-    pub fn insert<V>(mut self, key: &'static str, value: V) -> Self
+    pub fn insert<N, V>(mut self, key: N, value: V) -> Self
     where
+        N: AdhocKey<V> + Into<Cow<'static, str>>,
         V: AdhocValue + Clone,
     {
-        self.data.insert(key, Box::new(value));
+        self.insert_boxed(key.into(), Box::new(value));
         self
     }
+
+    /// Look up the value for `key`, if it is present and a `V`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// const LINE: status::Key<usize> = status::Key::new("Line");
+    ///
+    /// let c = status::AdhocContext::new().insert(LINE, 10).insert("Path", "Cargo.toml");
+    /// assert_eq!(c.get(LINE), Some(&10));
+    /// assert_eq!(c.get::<&str>("Path"), Some(&"Cargo.toml"));
+    /// assert_eq!(c.get::<String>("Path"), None);
+    /// ```
+    pub fn get<V: 'static>(&self, key: impl AdhocKey<V>) -> Option<&V> {
+        self.get_any(key.name())?.downcast_ref()
+    }
+
+    /// Look up the value for `key`, regardless of its type.
+    pub fn get_any(&self, key: &str) -> Option<&dyn AdhocValue> {
+        self.data
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_ref())
+    }
+
+    /// Returns `true` if there is a value for `key`.
+    pub fn contains(&self, key: impl AsRef<str>) -> bool {
+        self.get_any(key.as_ref()).is_some()
+    }
+
+    /// Remove the value for `key`, preserving the order of the remaining entries.
+    pub fn remove(&mut self, key: impl AsRef<str>) -> Option<Box<dyn AdhocValue>> {
+        let key = key.as_ref();
+        let index = self.data.iter().position(|(k, _)| k == key)?;
+        Some(self.data.remove(index).1)
+    }
+
+    /// Iterate over the entries, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &dyn AdhocValue)> {
+        self.data.iter().map(|(k, v)| (k.as_ref(), v.as_ref()))
+    }
+
+    /// Iterate over the keys, in insertion order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.data.iter().map(|(k, _)| k.as_ref())
+    }
+
+    fn insert_boxed(&mut self, key: Cow<'static, str>, value: Box<dyn AdhocValue>) {
+        match self.data.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.data.push((key, value)),
+        }
+    }
 }
 
 impl fmt::Display for AdhocContext {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (k, v) in self.data.iter() {
+        for (k, v) in &self.data {
             writeln!(f, "{}: {}", k, v)?;
         }
         Ok(())
@@ -84,19 +162,218 @@ impl fmt::Display for AdhocContext {
 
 impl Context for AdhocContext {
     fn update(mut self, replacements: Self) -> Self {
-        self.data.extend(replacements.data);
+        for (k, v) in replacements.data {
+            self.insert_boxed(k, v);
+        }
         self
     }
 
     fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
+
+    fn visit(&self, visitor: &mut dyn FnMut(&str, &dyn fmt::Display)) {
+        for (k, v) in &self.data {
+            visitor(k, v);
+        }
+    }
+}
+
+/// Typed key for an [`AdhocContext`] entry.
+///
+/// # Example
+///
+/// ```rust,compile_fail
+/// const LINE: status::Key<usize> = status::Key::new("Line");
+///
+/// let c = status::AdhocContext::new().insert(LINE, "10");
+/// ```
+pub struct Key<V> {
+    name: &'static str,
+    value: PhantomData<fn() -> V>,
+}
+
+impl<V> Key<V> {
+    /// Create a key named `name`.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            value: PhantomData,
+        }
+    }
+
+    /// The name of the key.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<V> Copy for Key<V> {}
+
+impl<V> Clone for Key<V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V> fmt::Debug for Key<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Key").field(&self.name).finish()
+    }
+}
+
+impl<V> AsRef<str> for Key<V> {
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+impl<V> From<Key<V>> for Cow<'static, str> {
+    fn from(key: Key<V>) -> Self {
+        Cow::Borrowed(key.name)
+    }
+}
+
+/// Look up a `V` in an [`AdhocContext`].
+///
+/// A plain string can hold any value while a [`Key`] only holds its own type.
+pub trait AdhocKey<V> {
+    /// The name of the key.
+    fn name(&self) -> &str;
+}
+
+impl<V> AdhocKey<V> for &str {
+    fn name(&self) -> &str {
+        self
+    }
+}
+
+impl<V> AdhocKey<V> for String {
+    fn name(&self) -> &str {
+        self
+    }
+}
+
+impl<V> AdhocKey<V> for Cow<'_, str> {
+    fn name(&self) -> &str {
+        self
+    }
+}
+
+impl<V> AdhocKey<V> for Key<V> {
+    fn name(&self) -> &str {
+        self.name
+    }
+}
+
+/// Context value that is hidden from users.
+///
+/// `Display` shows `[redacted]`, except when rendered by
+/// [`InternalStatus`][crate::InternalStatus], so a hand-written [`Context`] can hold `Secret`
+/// fields too.  `Debug` is always redacted.
+///
+/// Without the `std` feature, `Display` is always redacted.
+///
+/// # Example
+///
+/// ```rust
+/// use status::Secret;
+///
+/// let status = <status::Status>::new("Failed to authenticate")
+///     .context_with(|c| c.insert("Token", Secret::new("hunter2")));
+/// assert!(!status.to_string().contains("hunter2"));
+/// assert_eq!(
+///     status.get::<Secret<&str>>("Token").map(|t| *t.expose()),
+///     Some("hunter2")
+/// );
+/// # #[cfg(feature = "std")]
+/// assert!(status.into_internal().to_string().contains("hunter2"));
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Secret<V>(V);
+
+impl<V> Secret<V> {
+    /// Hide `value`.
+    pub fn new(value: V) -> Self {
+        Self(value)
+    }
+
+    /// Access the hidden value.
+    pub fn expose(&self) -> &V {
+        &self.0
+    }
+
+    /// Take the hidden value.
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for Secret<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if revealing() {
+            self.0.fmt(f)
+        } else {
+            f.write_str(REDACTED)
+        }
+    }
+}
+
+impl<V> fmt::Debug for Secret<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret({})", REDACTED)
+    }
+}
+
+pub(crate) const REDACTED: &str = "[redacted]";
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static REVEALING: Cell<bool> = const { Cell::new(false) };
+}
+
+#[cfg(feature = "std")]
+fn revealing() -> bool {
+    REVEALING.with(|r| r.get())
+}
+
+#[cfg(not(feature = "std"))]
+fn revealing() -> bool {
+    false
+}
+
+/// Formats a value, revealing [`Secret`]s.
+pub(crate) struct Reveal<'a>(pub(crate) &'a dyn fmt::Display);
+
+impl fmt::Display for Reveal<'_> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Restore on drop so a panicking value can't leave secrets revealed.
+        struct Restore(bool);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                REVEALING.with(|r| r.set(self.0));
+            }
+        }
+
+        let _restore = Restore(REVEALING.with(|r| r.replace(true)));
+        self.0.fmt(f)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
 }
 
 /// Trait alias for values in a [`AdhocContext`]
 pub trait AdhocValue: fmt::Display + fmt::Debug + Send + Sync + 'static {
     /// Clone the value
     fn clone_box(&self) -> Box<dyn AdhocValue>;
+
+    /// Access the value for downcasting.
+    fn as_any(&self) -> &dyn Any;
 }
 
 impl<V> AdhocValue for V
@@ -106,6 +383,22 @@ where
     fn clone_box(&self) -> Box<dyn AdhocValue> {
         Box::new(self.clone())
     }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl dyn AdhocValue {
+    /// Returns some reference to the value if it is of type `V`.
+    pub fn downcast_ref<V: 'static>(&self) -> Option<&V> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Returns `true` if the value is of type `V`.
+    pub fn is<V: 'static>(&self) -> bool {
+        self.as_any().is::<V>()
+    }
 }
 
 impl Clone for Box<dyn AdhocValue> {
@@ -136,4 +429,66 @@ mod test {
     fn adhoc_context() {
         assert_impl_all!(NoContext: Default, Clone, fmt::Debug, fmt::Display, Context);
     }
+
+    #[test]
+    fn adhoc_update_preserves_order() {
+        let c = AdhocContext::new()
+            .insert("First", 1)
+            .insert("Second", 2)
+            .update(AdhocContext::new().insert("Third", 3).insert("First", 10));
+        assert_eq!(c.to_string(), "First: 10\nSecond: 2\nThird: 3\n");
+    }
+
+    #[test]
+    fn adhoc_query() {
+        const LINE: Key<usize> = Key::new("Line");
+
+        let mut c = AdhocContext::new()
+            .insert(LINE, 10)
+            .insert("Path", "Cargo.toml")
+            .insert("Expected", 'a');
+        assert_eq!(c.get(LINE), Some(&10));
+        assert_eq!(c.get::<usize>("Line"), Some(&10));
+        assert_eq!(c.get::<u32>("Line"), None);
+        assert_eq!(c.get::<usize>("Column"), None);
+        assert!(c.contains(LINE));
+        assert!(c.contains("Path"));
+        assert_eq!(c.keys().collect::<Vec<_>>(), ["Line", "Path", "Expected"]);
+
+        let path = c.remove("Path").unwrap();
+        assert_eq!(path.downcast_ref::<&str>(), Some(&"Cargo.toml"));
+        assert!(c.remove("Path").is_none());
+        let entries: Vec<_> = c.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        assert_eq!(entries, ["Line=10", "Expected=a"]);
+    }
+
+    #[test]
+    fn secret() {
+        let c = AdhocContext::new()
+            .insert("Token", Secret::new("hunter2"))
+            .insert("Width", 10);
+        assert_eq!(c.to_string(), "Token: [redacted]\nWidth: 10\n");
+        assert_eq!(format!("{:#}", c), "Token: [redacted]\nWidth: 10\n");
+        assert!(!format!("{:?}", c).contains("hunter2"));
+        assert_eq!(
+            c.get::<Secret<&str>>("Token").map(|t| *t.expose()),
+            Some("hunter2")
+        );
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(Reveal(&c).to_string(), "Token: hunter2\nWidth: 10\n");
+            assert_eq!(c.to_string(), "Token: [redacted]\nWidth: 10\n");
+        }
+    }
+
+    #[test]
+    fn adhoc_owned_keys() {
+        let field = String::from("timeout");
+        let c = AdhocContext::new()
+            .insert("First", 1)
+            .insert(field.clone(), "30s")
+            .update(AdhocContext::new().insert(String::from("First"), 10));
+        assert_eq!(c.get::<&str>(field.as_str()), Some(&"30s"));
+        assert_eq!(c.to_string(), "First: 10\ntimeout: 30s\n");
+    }
 }
@@ -0,0 +1,270 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FromIterator;
+use core::slice;
+
+use crate::AdhocContext;
+use crate::Context;
+use crate::Kind;
+use crate::Status;
+use crate::Unkind;
+
+/// How serious a [`Status`] is.
+///
+/// Ordered from least to most severe.  [`Severity::Error`] is the default.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Severity {
+    /// Supplementary information.
+    Note,
+    /// Something is suspicious but the operation can continue.
+    Warning,
+    /// The operation failed.
+    #[default]
+    Error,
+}
+
+impl Severity {
+    /// Whether this is [`Severity::Error`].
+    pub fn is_error(&self) -> bool {
+        *self == Self::Error
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Note => "note",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Collect [`Status`]es to report them all at once, rather than stopping at the first.
+///
+/// # Example
+///
+/// ```rust
+/// use status::Diagnostics;
+/// use status::Severity;
+/// type Status = status::Status;
+///
+/// fn parse(line: &str) -> Result<u32, Status> {
+///     line.parse().map_err(|_| {
+///         <Status>::new("Invalid number").context_with(|c| c.insert("Line", line.to_owned()))
+///     })
+/// }
+///
+/// fn load(config: &str) -> Result<Vec<u32>, Status> {
+///     let mut diagnostics = Diagnostics::new();
+///     let mut values = Vec::new();
+///     for line in config.lines() {
+///         if line.is_empty() {
+///             diagnostics.push(<Status>::new("Empty line").with_severity(Severity::Warning));
+///         } else if let Some(value) = diagnostics.ok(parse(line)) {
+///             values.push(value);
+///         }
+///     }
+///     diagnostics.finish("Failed to load config")?;
+///     Ok(values)
+/// }
+///
+/// assert_eq!(load("1\n\n2").unwrap(), [1, 2]);
+/// let status = load("1\none\ntwo").unwrap_err();
+/// assert_eq!(status.related().count(), 2);
+/// ```
+#[derive(Debug)]
+pub struct Diagnostics<K: Kind = Unkind, C: Context = AdhocContext> {
+    statuses: Vec<Status<K, C>>,
+}
+
+impl<K: Kind, C: Context> Diagnostics<K, C> {
+    /// Create an empty collection.
+    pub fn new() -> Self {
+        Self {
+            statuses: Vec::new(),
+        }
+    }
+
+    /// Add a [`Status`].
+    pub fn push(&mut self, status: Status<K, C>) {
+        self.statuses.push(status);
+    }
+
+    /// Add the [`Status`] from `result`, if any, and continue with its value.
+    pub fn ok<T>(&mut self, result: Result<T, Status<K, C>>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(status) => {
+                self.push(status);
+                None
+            }
+        }
+    }
+
+    /// Whether any [`Status`] is a [`Severity::Error`].
+    pub fn has_errors(&self) -> bool {
+        self.statuses.iter().any(|s| s.severity().is_error())
+    }
+
+    /// The most severe [`Severity`], if any [`Status`] was added.
+    pub fn severity(&self) -> Option<Severity> {
+        self.statuses.iter().map(|s| s.severity()).max()
+    }
+
+    /// Number of [`Status`]es.
+    pub fn len(&self) -> usize {
+        self.statuses.len()
+    }
+
+    /// Whether no [`Status`] was added.
+    pub fn is_empty(&self) -> bool {
+        self.statuses.is_empty()
+    }
+
+    /// Iterate over the [`Status`]es.
+    pub fn iter(&self) -> slice::Iter<'_, Status<K, C>> {
+        self.statuses.iter()
+    }
+
+    /// Order from most to least severe, keeping the order within a [`Severity`].
+    pub fn sort(&mut self) {
+        self.statuses
+            .sort_by_key(|s| core::cmp::Reverse(s.severity()));
+    }
+
+    /// Combine into a single [`Status`], with each [`Status`] as a related error.
+    ///
+    /// The related errors are sorted, see [`Diagnostics::sort`], and the [`Status`] takes the most
+    /// severe [`Severity`].
+    pub fn into_status<K2, C2, U>(mut self, kind: U) -> Status<K2, C2>
+    where
+        K2: Kind,
+        C2: Context,
+        U: Into<K2>,
+    {
+        self.sort();
+        let severity = self.severity().unwrap_or_default();
+        self.statuses.into_iter().fold(
+            Status::<K2, C2>::new(kind).with_severity(severity),
+            |status, s| status.with_related(s),
+        )
+    }
+
+    /// Fail if there are any errors, otherwise return the remaining [`Status`]es.
+    ///
+    /// See [`Diagnostics::into_status`].
+    pub fn finish<K2, C2, U>(self, kind: U) -> Result<Vec<Status<K, C>>, Status<K2, C2>>
+    where
+        K2: Kind,
+        C2: Context,
+        U: Into<K2>,
+    {
+        if self.has_errors() {
+            Err(self.into_status(kind))
+        } else {
+            Ok(self.statuses)
+        }
+    }
+}
+
+impl<K: Kind, C: Context> Default for Diagnostics<K, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Kind, C: Context> Extend<Status<K, C>> for Diagnostics<K, C> {
+    fn extend<I: IntoIterator<Item = Status<K, C>>>(&mut self, iter: I) {
+        self.statuses.extend(iter);
+    }
+}
+
+impl<K: Kind, C: Context> FromIterator<Status<K, C>> for Diagnostics<K, C> {
+    fn from_iter<I: IntoIterator<Item = Status<K, C>>>(iter: I) -> Self {
+        Self {
+            statuses: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<K: Kind, C: Context> IntoIterator for Diagnostics<K, C> {
+    type Item = Status<K, C>;
+    type IntoIter = alloc::vec::IntoIter<Status<K, C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.statuses.into_iter()
+    }
+}
+
+impl<'a, K: Kind, C: Context> IntoIterator for &'a Diagnostics<K, C> {
+    type Item = &'a Status<K, C>;
+    type IntoIter = slice::Iter<'a, Status<K, C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn severity() {
+        assert!(Severity::Note < Severity::Warning);
+        assert!(Severity::Warning < Severity::Error);
+        assert_eq!(Severity::default(), Severity::Error);
+        assert_eq!(Severity::Warning.to_string(), "warning");
+    }
+
+    #[test]
+    fn diagnostics() {
+        let mut diagnostics = Diagnostics::<Unkind, AdhocContext>::new();
+        assert_eq!(diagnostics.severity(), None);
+        diagnostics.push(<Status>::new("Unused key").with_severity(Severity::Note));
+        diagnostics.push(<Status>::new("Deprecated key").with_severity(Severity::Warning));
+        assert!(!diagnostics.has_errors());
+        diagnostics.push(<Status>::new("Missing key"));
+        assert!(diagnostics.has_errors());
+
+        diagnostics.sort();
+        let kinds: Vec<_> = diagnostics
+            .iter()
+            .map(|s| s.kind().to_string().trim_end().to_owned())
+            .collect();
+        assert_eq!(kinds, ["Missing key", "Deprecated key", "Unused key"]);
+
+        let status: Status = diagnostics.finish("Invalid config").unwrap_err();
+        assert_eq!(status.severity(), Severity::Error);
+        assert_eq!(
+            status.to_string(),
+            "Invalid config
+
+
+Related:
+├─ Missing key
+├─ warning: Deprecated key
+└─ note: Unused key
+"
+        );
+    }
+
+    #[test]
+    fn finish_warnings() {
+        let diagnostics: Diagnostics =
+            vec![<Status>::new("Deprecated key").with_severity(Severity::Warning)]
+                .into_iter()
+                .collect();
+        let warnings = diagnostics
+            .finish::<Unkind, AdhocContext, _>("Invalid config")
+            .unwrap();
+        assert_eq!(warnings.len(), 1);
+
+        let status: Status = Diagnostics::<Unkind, AdhocContext>::new().into_status("Nothing");
+        assert_eq!(status.severity(), Severity::Error);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::Kind;
+use crate::Unkind;
+
+/// Map a [`Kind`] to a process exit code, for when a [`Status`][crate::Status] ends `main`.
+///
+/// By default, every [`Kind`] exits with [`Sysexit::Software`].  See
+/// [`TerminatingStatus`][crate::TerminatingStatus].
+///
+/// # Example
+///
+/// ```rust
+/// use status::ExitCode;
+/// use status::Sysexit;
+///
+/// #[derive(Copy, Clone, Debug, derive_more::Display)]
+/// enum ErrorKind {
+///   #[display(fmt = "Failed to read file")]
+///   Read,
+///   #[display(fmt = "Invalid argument")]
+///   Usage,
+/// }
+///
+/// impl ExitCode for ErrorKind {
+///     fn exit_code(&self) -> u8 {
+///         match self {
+///             Self::Read => Sysexit::NoInput.code(),
+///             Self::Usage => Sysexit::Usage.code(),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(ErrorKind::Usage.exit_code(), 64);
+/// ```
+pub trait ExitCode: Kind {
+    /// The code to exit the process with.
+    fn exit_code(&self) -> u8 {
+        Sysexit::Software.code()
+    }
+}
+
+impl ExitCode for Unkind {}
+
+/// Exit codes from BSD's `sysexits.h`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Sysexit {
+    /// The command was used incorrectly, e.g. with the wrong number of arguments.
+    Usage = 64,
+    /// The input data was incorrect in some way.
+    DataErr = 65,
+    /// An input file did not exist or was not readable.
+    NoInput = 66,
+    /// The user specified did not exist.
+    NoUser = 67,
+    /// The host specified did not exist.
+    NoHost = 68,
+    /// A service is unavailable.
+    Unavailable = 69,
+    /// An internal software error has been detected.
+    Software = 70,
+    /// An operating system error has been detected.
+    OsErr = 71,
+    /// Some system file does not exist, cannot be opened, or has some sort of error.
+    OsFile = 72,
+    /// A user specified output file cannot be created.
+    CantCreat = 73,
+    /// An error occurred while doing I/O on some file.
+    IoErr = 74,
+    /// Temporary failure, indicating something that is not really an error.
+    TempFail = 75,
+    /// The remote system returned something that was "not possible" during a protocol exchange.
+    Protocol = 76,
+    /// You did not have sufficient permission to perform the operation.
+    NoPerm = 77,
+    /// Something was found in an unconfigured or misconfigured state.
+    Config = 78,
+}
+
+impl Sysexit {
+    /// The numeric exit code.
+    pub const fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+impl From<Sysexit> for u8 {
+    fn from(code: Sysexit) -> Self {
+        code.code()
+    }
+}
@@ -1,12 +1,13 @@
 use crate::Context;
 use crate::Kind;
+use crate::SourceError;
 use crate::Status;
 
 /// Modify the [`Status`] inline for error handling.
-pub trait ResultStatusExt<C, F>
+pub trait ResultStatusExt<T, K, C>
 where
+    K: Kind,
     C: Context,
-    F: Fn(C) -> C,
 {
     /// Replace fields in the [`Context`] with those populated in `replacements`.
     /// ```rust
@@ -31,16 +32,278 @@ where
     /// #     Ok(())
     /// # }
     /// ```
-    fn context_with(self, replacements: F) -> Self;
+    fn context_with<F>(self, replacements: F) -> Self
+    where
+        F: Fn(C) -> C;
+
+    /// Convert the [`Kind`] and [`Context`], see [`Status::map`].
+    ///
+    /// This is not named `map` to avoid being shadowed by [`Result::map`].
+    fn map_status<K2, C2, F>(self, f: F) -> Result<T, Status<K2, C2>>
+    where
+        K2: Kind,
+        C2: Context,
+        F: FnOnce(K, C) -> (K2, C2);
+
+    /// Convert the [`Kind`], see [`Status::map_kind`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use status::ResultStatusExt;
+    ///
+    /// #[derive(Copy, Clone, Debug, derive_more::Display)]
+    /// enum StorageKind {
+    ///   #[display(fmt = "Disk is full")]
+    ///   Full,
+    /// }
+    ///
+    /// #[derive(Copy, Clone, Debug, derive_more::Display, derive_more::From)]
+    /// enum AppKind {
+    ///   #[display(fmt = "Failed to save")]
+    ///   Save(StorageKind),
+    /// }
+    ///
+    /// fn write() -> Result<(), status::Status<StorageKind>> {
+    ///     Err(status::Status::new(StorageKind::Full))
+    /// }
+    ///
+    /// fn save() -> Result<(), status::Status<AppKind>> {
+    ///     write().map_kind(AppKind::from)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    fn map_kind<K2, F>(self, f: F) -> Result<T, Status<K2, C>>
+    where
+        K2: Kind,
+        F: FnOnce(K) -> K2;
+
+    /// Convert the [`Context`], see [`Status::map_context`].
+    fn map_context<C2, F>(self, f: F) -> Result<T, Status<K, C2>>
+    where
+        C2: Context,
+        F: FnOnce(C) -> C2;
 }
 
-impl<T, K, C, F> ResultStatusExt<C, F> for Result<T, Status<K, C>>
+impl<T, K, C> ResultStatusExt<T, K, C> for Result<T, Status<K, C>>
 where
     K: Kind,
     C: Context,
-    F: Fn(C) -> C,
 {
-    fn context_with(self, replacements: F) -> Self {
+    fn context_with<F>(self, replacements: F) -> Self
+    where
+        F: Fn(C) -> C,
+    {
         self.map_err(|e| e.context_with(replacements))
     }
+
+    fn map_status<K2, C2, F>(self, f: F) -> Result<T, Status<K2, C2>>
+    where
+        K2: Kind,
+        C2: Context,
+        F: FnOnce(K, C) -> (K2, C2),
+    {
+        self.map_err(|e| e.map(f))
+    }
+
+    fn map_kind<K2, F>(self, f: F) -> Result<T, Status<K2, C>>
+    where
+        K2: Kind,
+        F: FnOnce(K) -> K2,
+    {
+        self.map_err(|e| e.map_kind(f))
+    }
+
+    fn map_context<C2, F>(self, f: F) -> Result<T, Status<K, C2>>
+    where
+        C2: Context,
+        F: FnOnce(C) -> C2,
+    {
+        self.map_err(|e| e.map_context(f))
+    }
+}
+
+/// Create a [`Status`] inline when a value is missing.
+pub trait OptionStatusExt<T> {
+    /// Transform `None` into a [`Status`] of `kind`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use status::OptionStatusExt;
+    ///
+    /// fn home() -> Result<String, status::Status> {
+    ///     std::env::var("HOME").ok().ok_or_status("HOME is not set")
+    /// }
+    /// ```
+    fn ok_or_status<K, C, U>(self, kind: U) -> Result<T, Status<K, C>>
+    where
+        K: Kind,
+        C: Context,
+        U: Into<K>;
+}
+
+impl<T> OptionStatusExt<T> for Option<T> {
+    fn ok_or_status<K, C, U>(self, kind: U) -> Result<T, Status<K, C>>
+    where
+        K: Kind,
+        C: Context,
+        U: Into<K>,
+    {
+        self.ok_or_else(|| Status::new(kind))
+    }
+}
+
+/// Create a [`Status`] inline from any other error.
+///
+/// As `?` converts the error, the [`Kind`] and [`Context`] can't be inferred through it.  Either
+/// return the `Result` directly or annotate the `Status`.
+pub trait ResultErrorExt<T> {
+    /// Wrap the error in a [`Status`] of `kind`, as an internal source.
+    ///
+    /// See [`Status::with_internal`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use status::ResultErrorExt;
+    ///
+    /// fn read_file(path: &str) -> Result<String, status::Status> {
+    ///     std::fs::read_to_string(path).status("Failed to read file")
+    /// }
+    /// ```
+    fn status<K, C, U>(self, kind: U) -> Result<T, Status<K, C>>
+    where
+        K: Kind,
+        C: Context,
+        U: Into<K>;
+
+    /// Wrap the error in a [`Status`] of `kind` with `context`, as an internal source.
+    ///
+    /// The [`Context`] is not known yet when the closure is checked, so its parameter needs a
+    /// type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use status::AdhocContext;
+    /// use status::ResultErrorExt;
+    ///
+    /// fn read_file(path: &str) -> Result<String, status::Status> {
+    ///     std::fs::read_to_string(path).status_with("Failed to read file", |c: AdhocContext| {
+    ///         c.insert("Path", path.to_owned())
+    ///     })
+    /// }
+    /// ```
+    fn status_with<K, C, U, F>(self, kind: U, context: F) -> Result<T, Status<K, C>>
+    where
+        K: Kind,
+        C: Context,
+        U: Into<K>,
+        F: Fn(C) -> C;
+
+    /// Wrap the error in a [`Status`] of `kind`, as a public source.
+    ///
+    /// See [`Status::with_source`].
+    fn status_public<K, C, U>(self, kind: U) -> Result<T, Status<K, C>>
+    where
+        K: Kind,
+        C: Context,
+        U: Into<K>;
+
+    /// Wrap the error in a [`Status`] of `kind` with `context`, as a public source.
+    fn status_public_with<K, C, U, F>(self, kind: U, context: F) -> Result<T, Status<K, C>>
+    where
+        K: Kind,
+        C: Context,
+        U: Into<K>,
+        F: Fn(C) -> C;
+}
+
+/// For any error that [`Status::with_source`] accepts.
+impl<T, E> ResultErrorExt<T> for Result<T, E>
+where
+    E: SourceError,
+{
+    fn status<K, C, U>(self, kind: U) -> Result<T, Status<K, C>>
+    where
+        K: Kind,
+        C: Context,
+        U: Into<K>,
+    {
+        self.map_err(|e| Status::<K, C>::new(kind).with_internal(e))
+    }
+
+    fn status_with<K, C, U, F>(self, kind: U, context: F) -> Result<T, Status<K, C>>
+    where
+        K: Kind,
+        C: Context,
+        U: Into<K>,
+        F: Fn(C) -> C,
+    {
+        self.map_err(|e| {
+            Status::<K, C>::new(kind)
+                .with_internal(e)
+                .context_with(context)
+        })
+    }
+
+    fn status_public<K, C, U>(self, kind: U) -> Result<T, Status<K, C>>
+    where
+        K: Kind,
+        C: Context,
+        U: Into<K>,
+    {
+        self.map_err(|e| Status::<K, C>::new(kind).with_source(e))
+    }
+
+    fn status_public_with<K, C, U, F>(self, kind: U, context: F) -> Result<T, Status<K, C>>
+    where
+        K: Kind,
+        C: Context,
+        U: Into<K>,
+        F: Fn(C) -> C,
+    {
+        self.map_err(|e| {
+            Status::<K, C>::new(kind)
+                .with_source(e)
+                .context_with(context)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io;
+
+    use crate::AdhocContext;
+
+    fn read(path: &str) -> Result<String, io::Error> {
+        Err(io::Error::new(io::ErrorKind::NotFound, path.to_owned()))
+    }
+
+    #[test]
+    fn ok_or_status() {
+        let status: Status = None::<()>.ok_or_status("Missing key").unwrap_err();
+        assert_eq!(status.kind().to_string().trim_end(), "Missing key");
+        let value: Result<_, Status> = Some(5).ok_or_status("Missing key");
+        assert_eq!(value.unwrap(), 5);
+    }
+
+    #[test]
+    fn result_error() {
+        let status: Status = read("Cargo.toml").status("Failed to read").unwrap_err();
+        assert_eq!(status.sources().count(), 0);
+        assert_eq!(status.into_internal().sources().count(), 1);
+
+        let result: Result<_, Status> = read("Cargo.toml")
+            .status_public_with("Failed to read", |c: AdhocContext| {
+                c.insert("Path", "Cargo.toml")
+            });
+        let status = result.unwrap_err();
+        assert_eq!(status.sources().count(), 1);
+        assert_eq!(status.get::<&str>("Path"), Some(&"Cargo.toml"));
+    }
 }
@@ -0,0 +1,295 @@
+//! C API for passing a [`Status`] across FFI.
+//!
+//! A Rust library hands a [`Status`] to C with [`into_raw`], and C inspects it through the
+//! `status_*` functions declared in `include/status.h` before releasing it with [`status_free`].
+//!
+//! All strings are owned by the `status_t` and are valid until [`status_free`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use status::ffi::status_t;
+//!
+//! #[derive(Copy, Clone, Debug, derive_more::Display)]
+//! enum ErrorKind {
+//!   #[display(fmt = "Failed to read file")]
+//!   Read,
+//! }
+//!
+//! impl status::KindCode for ErrorKind {
+//!     fn code(&self) -> status::Code {
+//!         status::Code::Int(1042)
+//!     }
+//!
+//!     fn from_code(code: status::Code) -> Option<Self> {
+//!         (code == status::Code::Int(1042)).then(|| Self::Read)
+//!     }
+//! }
+//!
+//! #[no_mangle]
+//! pub extern "C" fn read_file() -> *mut status_t {
+//!     match std::fs::read_to_string("Cargo.toml") {
+//!         Ok(_) => std::ptr::null_mut(),
+//!         Err(e) => status::ffi::into_raw(
+//!             status::Status::<ErrorKind>::new(ErrorKind::Read).with_internal(e),
+//!         ),
+//!     }
+//! }
+//! ```
+
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+
+use crate::Code;
+use crate::Context;
+use crate::KindCode;
+use crate::Status;
+
+/// Opaque snapshot of a [`Status`] for C.
+///
+/// Only the public sources are included.
+#[allow(non_camel_case_types)]
+#[derive(Debug)]
+pub struct status_t {
+    code: Code,
+    code_str: CString,
+    kind: CString,
+    message: CString,
+    context: Vec<(CString, CString)>,
+    sources: Vec<CString>,
+}
+
+impl status_t {
+    /// Snapshot `status` for C.
+    pub fn new<K: KindCode, C: Context>(status: &Status<K, C>) -> Self {
+        let code = status.code();
+        let mut context = Vec::new();
+        status.context().visit(&mut |label, value| {
+            context.push((c_string(label), c_string(&value.to_string())));
+        });
+        Self {
+            code_str: c_string(&code.to_string()),
+            code,
+            kind: c_string(status.kind().to_string().trim_end()),
+            message: c_string(&status.to_string()),
+            context,
+            sources: status.sources().map(|s| c_string(&s.to_string())).collect(),
+        }
+    }
+}
+
+/// Hand `status` over to C.
+///
+/// C must release it with [`status_free`].
+pub fn into_raw<K: KindCode, C: Context>(status: Status<K, C>) -> *mut status_t {
+    Box::into_raw(Box::new(status_t::new(&status)))
+}
+
+fn c_string(s: &str) -> CString {
+    CString::new(s.replace('\0', "\\0")).expect("NULs were replaced")
+}
+
+fn as_ptr(s: Option<&CString>) -> *const c_char {
+    s.map(|s| s.as_ptr()).unwrap_or(ptr::null())
+}
+
+/// Release a `status_t`.
+///
+/// # Safety
+///
+/// `status` must be NULL or come from [`into_raw`], and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn status_free(status: *mut status_t) {
+    if !status.is_null() {
+        drop(Box::from_raw(status));
+    }
+}
+
+/// The integer code of the status' kind.
+///
+/// Returns `false` if the code is not an integer or `status` is NULL.
+///
+/// # Safety
+///
+/// `status` must be NULL or a live `status_t` and `code` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn status_code(status: *const status_t, code: *mut i64) -> bool {
+    match status.as_ref().and_then(|s| s.code.as_int()) {
+        Some(value) => {
+            *code = value;
+            true
+        }
+        None => false,
+    }
+}
+
+/// The code of the status' kind, rendered as a string.
+///
+/// Returns NULL if `status` is NULL.
+///
+/// # Safety
+///
+/// `status` must be NULL or a live `status_t`.
+#[no_mangle]
+pub unsafe extern "C" fn status_code_str(status: *const status_t) -> *const c_char {
+    as_ptr(status.as_ref().map(|s| &s.code_str))
+}
+
+/// The message for the status' kind.
+///
+/// Returns NULL if `status` is NULL.
+///
+/// # Safety
+///
+/// `status` must be NULL or a live `status_t`.
+#[no_mangle]
+pub unsafe extern "C" fn status_kind(status: *const status_t) -> *const c_char {
+    as_ptr(status.as_ref().map(|s| &s.kind))
+}
+
+/// The status rendered for the user, including its context.
+///
+/// Returns NULL if `status` is NULL.
+///
+/// # Safety
+///
+/// `status` must be NULL or a live `status_t`.
+#[no_mangle]
+pub unsafe extern "C" fn status_message(status: *const status_t) -> *const c_char {
+    as_ptr(status.as_ref().map(|s| &s.message))
+}
+
+/// The number of context entries.
+///
+/// # Safety
+///
+/// `status` must be NULL or a live `status_t`.
+#[no_mangle]
+pub unsafe extern "C" fn status_context_len(status: *const status_t) -> usize {
+    status.as_ref().map(|s| s.context.len()).unwrap_or(0)
+}
+
+/// The key of the context entry at `index`.
+///
+/// Returns NULL if `index` is out of bounds.
+///
+/// # Safety
+///
+/// `status` must be NULL or a live `status_t`.
+#[no_mangle]
+pub unsafe extern "C" fn status_context_key(
+    status: *const status_t,
+    index: usize,
+) -> *const c_char {
+    as_ptr(
+        status
+            .as_ref()
+            .and_then(|s| s.context.get(index))
+            .map(|(k, _)| k),
+    )
+}
+
+/// The value of the context entry at `index`.
+///
+/// Returns NULL if `index` is out of bounds.
+///
+/// # Safety
+///
+/// `status` must be NULL or a live `status_t`.
+#[no_mangle]
+pub unsafe extern "C" fn status_context_value(
+    status: *const status_t,
+    index: usize,
+) -> *const c_char {
+    as_ptr(
+        status
+            .as_ref()
+            .and_then(|s| s.context.get(index))
+            .map(|(_, v)| v),
+    )
+}
+
+/// The number of public sources in the chain.
+///
+/// # Safety
+///
+/// `status` must be NULL or a live `status_t`.
+#[no_mangle]
+pub unsafe extern "C" fn status_sources_len(status: *const status_t) -> usize {
+    status.as_ref().map(|s| s.sources.len()).unwrap_or(0)
+}
+
+/// The message of the source at `index`, starting from the immediate source.
+///
+/// Returns NULL if `index` is out of bounds.
+///
+/// # Safety
+///
+/// `status` must be NULL or a live `status_t`.
+#[no_mangle]
+pub unsafe extern "C" fn status_source(status: *const status_t, index: usize) -> *const c_char {
+    as_ptr(status.as_ref().and_then(|s| s.sources.get(index)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::ffi::CStr;
+    use std::io;
+
+    #[derive(Copy, Clone, Debug, PartialEq, derive_more::Display)]
+    enum ErrorKind {
+        #[display(fmt = "Failed to read file")]
+        Read,
+    }
+
+    impl KindCode for ErrorKind {
+        fn code(&self) -> Code {
+            Code::from("io.read.E1042")
+        }
+
+        fn from_code(_code: Code) -> Option<Self> {
+            Some(Self::Read)
+        }
+    }
+
+    unsafe fn string(s: *const c_char) -> Option<&'static str> {
+        s.as_ref().map(|s| CStr::from_ptr(s).to_str().unwrap())
+    }
+
+    #[test]
+    fn accessors() {
+        let status = <Status<ErrorKind>>::new(ErrorKind::Read)
+            .with_source(io::Error::new(io::ErrorKind::NotFound, "missing\0file"))
+            .context_with(|c| c.insert("Path", "Cargo.toml"));
+        let status = into_raw(status);
+        unsafe {
+            let mut code = 0;
+            assert!(!status_code(status, &mut code));
+            assert_eq!(string(status_code_str(status)), Some("io.read.E1042"));
+            assert_eq!(string(status_kind(status)), Some("Failed to read file"));
+            assert_eq!(
+                string(status_message(status)),
+                Some("Failed to read file\n\nPath: Cargo.toml\n\n")
+            );
+            assert_eq!(status_context_len(status), 1);
+            assert_eq!(string(status_context_key(status, 0)), Some("Path"));
+            assert_eq!(string(status_context_value(status, 0)), Some("Cargo.toml"));
+            assert_eq!(string(status_context_key(status, 1)), None);
+            assert_eq!(status_sources_len(status), 1);
+            assert_eq!(string(status_source(status, 0)), Some("missing\\0file"));
+            status_free(status);
+        }
+    }
+
+    #[test]
+    fn null() {
+        unsafe {
+            assert!(status_kind(ptr::null()).is_null());
+            assert_eq!(status_context_len(ptr::null()), 0);
+            status_free(ptr::null_mut());
+        }
+    }
+}
@@ -1,9 +1,14 @@
-use std::error;
-use std::fmt;
+use core::error;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::backtrace::Backtrace;
 
 use crate::Chain;
 use crate::Context;
+use crate::Json;
 use crate::Kind;
+use crate::Related;
+use crate::Report;
 use crate::Status;
 
 /// View of [`Status`], exposing implementation details.
@@ -24,7 +29,7 @@ use crate::Status;
 /// }
 /// ```
 #[derive(Debug)]
-pub struct InternalStatus<K: Kind, C: Context>(Status<K, C>);
+pub struct InternalStatus<K: Kind, C: Context>(pub(crate) Status<K, C>);
 
 impl<K: Kind, C: Context> InternalStatus<K, C> {
     pub(crate) fn new(err: Status<K, C>) -> Self {
@@ -32,14 +37,89 @@ impl<K: Kind, C: Context> InternalStatus<K, C> {
     }
 
     /// An iterator for the chain of sources, private or public.
-    pub fn sources(&self) -> Chain {
-        Chain::new(error::Error::source(self))
+    ///
+    /// This continues through the private sources of any nested [`Status`], whatever its
+    /// [`Kind`] and [`Context`].  Without the `std` feature, only nested `Status<K, C>`s are
+    /// continued through.
+    pub fn sources(&self) -> Chain<'_> {
+        Chain::internal::<K, C>(error::Error::source(self))
+    }
+
+    /// The first source of type `T`, private or public, searching the whole chain.
+    ///
+    /// Like [`InternalStatus::sources`], this searches the private sources of nested [`Status`]es.
+    /// See [`Status::find_source`].
+    pub fn find_source<T: error::Error + 'static>(&self) -> Option<&T> {
+        self.sources().downcast::<T>().next()
+    }
+
+    /// An iterator over the related errors, private or public.
+    pub fn related(&self) -> Related<'_> {
+        Related::new(&(self.0).inner.related, true)
+    }
+
+    /// Render as a JSON object, for structured logs, including private sources.
+    ///
+    /// See [`Json`].
+    pub fn json(&self) -> Json<'_, K, C> {
+        Json::new(&self.0, true)
+    }
+
+    /// The backtrace captured when the [`Status`] was created.
+    ///
+    /// Like `std`, this is only captured when enabled by `RUST_LIB_BACKTRACE` or `RUST_BACKTRACE`.
+    #[cfg(feature = "std")]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        (self.0).inner.backtrace.as_ref()
     }
 }
 
 impl<K: Kind, C: Context> fmt::Display for InternalStatus<K, C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "{}", self.0)
+        self.0.render(f, true)?;
+        writeln!(f)?;
+        #[cfg(feature = "tracing")]
+        {
+            if let Some(span_trace) = self.span_trace() {
+                writeln!(f, "Span trace:")?;
+                writeln!(f, "{}", span_trace)?;
+            }
+        }
+        #[cfg(feature = "std")]
+        {
+            if let Some(backtrace) = self.backtrace() {
+                writeln!(f, "Backtrace:")?;
+                writeln!(f, "{}", backtrace)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<K: Kind, C: Context> Report for InternalStatus<K, C> {
+    fn kind(&self) -> Option<&dyn fmt::Display> {
+        Report::kind(&self.0)
+    }
+
+    fn severity(&self) -> crate::Severity {
+        self.0.severity()
+    }
+
+    fn context(&self) -> Option<&dyn fmt::Display> {
+        Report::context(&self.0)
+    }
+
+    fn visit_context(&self, visitor: &mut dyn FnMut(&str, &dyn fmt::Display)) {
+        self.0.visit_context(visitor)
+    }
+
+    fn related(&self) -> Related<'_> {
+        InternalStatus::related(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn backtrace(&self) -> Option<&Backtrace> {
+        InternalStatus::backtrace(self)
     }
 }
 
@@ -67,4 +147,65 @@ mod test {
         #[cfg(feature = "send_sync")]
         assert_impl_all!(InternalStatus<Unkind, NoContext>: Send, Sync);
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn backtrace() {
+        let mut status = Status::<Unkind, NoContext>::new("Died");
+        status.inner.backtrace = Some(Backtrace::force_capture());
+        assert!(!status.to_string().contains("Backtrace:"));
+        assert!(status.into_internal().to_string().contains("Backtrace:"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn find_nested_internal() {
+        use std::io;
+
+        let read = Status::<Unkind, NoContext>::new("Failed to read file")
+            .with_internal(io::Error::other("Truncated"));
+        let load = Status::<Unkind, NoContext>::new("Failed to load config").with_internal(read);
+        let status = Status::<Unkind, NoContext>::new("Failed to start").with_source(load);
+        assert!(status.find_source::<io::Error>().is_none());
+
+        let status = status.into_internal();
+        assert_eq!(status.sources().count(), 3);
+        assert_eq!(
+            status.find_source::<io::Error>().map(|e| e.to_string()),
+            Some("Truncated".to_owned())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn find_nested_internal_types() {
+        use std::io;
+
+        #[derive(Copy, Clone, Debug, PartialEq, derive_more::Display)]
+        enum IoKind {
+            #[display(fmt = "Failed to read file")]
+            Read,
+        }
+
+        let read = Status::<IoKind, NoContext>::new(IoKind::Read)
+            .with_internal(io::Error::new(io::ErrorKind::TimedOut, "Timed out"));
+        let load = <Status>::new("Failed to load config")
+            .context_with(|c| c.insert("Path", "Cargo.toml"))
+            .with_internal(read);
+        let status = Status::<Unkind, NoContext>::new("Failed to start").with_internal(load);
+        assert!(status.find_source::<io::Error>().is_none());
+
+        let status = status.into_internal();
+        assert_eq!(status.sources().count(), 3);
+        assert_eq!(
+            status
+                .find_source::<Status<IoKind, NoContext>>()
+                .map(|s| s.kind()),
+            Some(IoKind::Read)
+        );
+        assert_eq!(
+            status.find_source::<io::Error>().map(|e| e.kind()),
+            Some(io::ErrorKind::TimedOut)
+        );
+    }
 }
@@ -0,0 +1,325 @@
+use alloc::string::ToString;
+use core::fmt;
+use core::fmt::Write;
+
+use crate::context::Reveal;
+use crate::Code;
+use crate::Context;
+use crate::Kind;
+use crate::KindCode;
+use crate::Source;
+use crate::Status;
+
+/// A [`Status`] rendered as a single-line JSON object, for structured logs.
+///
+/// See [`Status::json`] and [`InternalStatus::json`][crate::InternalStatus::json].
+///
+/// ```json
+/// {
+///   "kind": "Failed to read file",
+///   "code": "io.read.E1042",
+///   "severity": "error",
+///   "context": {"Path": "Cargo.toml"},
+///   "chain": [{"type": "std::io::error::Error", "message": "No such file", "visibility": "public"}],
+///   "related": [[{"type": "std::io::error::Error", "message": "Retried", "visibility": "public"}]]
+/// }
+/// ```
+/// - `code` is only present after [`Json::with_code`].
+/// - `severity` is the [`Severity`][crate::Severity], in lowercase.
+/// - `context` maps each label to the `Display` of its value, revealing
+///   [`Secret`][crate::Secret]s for an [`InternalStatus`][crate::InternalStatus].  If the
+///   [`Context`] doesn't implement [`Context::visit`], it is the `Display` of the whole context
+///   instead.
+/// - `chain` is the source followed by its sources.  Only the `type` of the source itself is
+///   known, the rest are `null`.
+/// - `related` is only present when there are related errors, each with its own chain.
+///
+/// Nothing is written after the object, so use `writeln!` for JSON lines.
+///
+/// # Example
+///
+/// ```rust
+/// let status = <status::Status>::new("Failed to read file")
+///     .context_with(|c| c.insert("Path", "Cargo.toml"));
+/// assert_eq!(
+///     status.json().to_string(),
+///     r#"{"kind":"Failed to read file","severity":"error","context":{"Path":"Cargo.toml"},"chain":[]}"#
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Json<'a, K: Kind, C: Context> {
+    status: &'a Status<K, C>,
+    internal: bool,
+    code: Option<Code>,
+}
+
+impl<'a, K: Kind, C: Context> Json<'a, K, C> {
+    pub(crate) fn new(status: &'a Status<K, C>, internal: bool) -> Self {
+        Self {
+            status,
+            internal,
+            code: None,
+        }
+    }
+}
+
+impl<K: KindCode, C: Context> Json<'_, K, C> {
+    /// Include the [`Code`] of the [`Kind`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[derive(Copy, Clone, Debug, derive_more::Display)]
+    /// enum ErrorKind {
+    ///   #[display(fmt = "Failed to read file")]
+    ///   Read,
+    /// }
+    ///
+    /// impl status::KindCode for ErrorKind {
+    ///     fn code(&self) -> status::Code {
+    ///         status::Code::Int(1042)
+    ///     }
+    ///
+    ///     fn from_code(code: status::Code) -> Option<Self> {
+    ///         (code == status::Code::Int(1042)).then(|| Self::Read)
+    ///     }
+    /// }
+    ///
+    /// let status = status::Status::<ErrorKind, status::NoContext>::new(ErrorKind::Read);
+    /// assert_eq!(
+    ///     status.json().with_code().to_string(),
+    ///     r#"{"kind":"Failed to read file","code":1042,"severity":"error","context":{},"chain":[]}"#
+    /// );
+    /// ```
+    pub fn with_code(mut self) -> Self {
+        self.code = Some(self.status.code());
+        self
+    }
+}
+
+impl<K: Kind, C: Context> fmt::Display for Json<'_, K, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = &self.status.inner;
+
+        f.write_str("{\"kind\":")?;
+        string(f, &inner.kind.to_string().trim_end())?;
+
+        match &self.code {
+            Some(Code::Int(code)) => write!(f, ",\"code\":{}", code)?,
+            Some(Code::Str(code)) => {
+                f.write_str(",\"code\":")?;
+                string(f, code)?;
+            }
+            None => {}
+        }
+
+        f.write_str(",\"severity\":")?;
+        string(f, &inner.severity)?;
+
+        f.write_str(",\"context\":")?;
+        context(f, &inner.data, self.internal)?;
+
+        f.write_str(",\"chain\":")?;
+        chain(f, &inner.source, self.internal)?;
+
+        if has_related(&inner.related, self.internal) {
+            f.write_str(",\"related\":")?;
+            related(f, &inner.related, self.internal)?;
+        }
+
+        f.write_char('}')
+    }
+}
+
+/// Write `context` as a JSON object of labels to values.
+pub(crate) fn context<C: Context>(
+    f: &mut fmt::Formatter<'_>,
+    context: &C,
+    internal: bool,
+) -> fmt::Result {
+    let mut fields = 0;
+    context.visit(&mut |_, _| fields += 1);
+    if fields == 0 && !context.is_empty() {
+        let context = if internal {
+            Reveal(context).to_string()
+        } else {
+            context.to_string()
+        };
+        return string(f, &context.trim_end());
+    }
+
+    f.write_char('{')?;
+    let mut result = Ok(());
+    let mut first = true;
+    context.visit(&mut |label, value| {
+        if result.is_ok() {
+            if !first {
+                result = f.write_char(',');
+            }
+            result = result
+                .and_then(|_| string(f, &label))
+                .and_then(|_| f.write_char(':'))
+                .and_then(|_| {
+                    if internal {
+                        string(f, &Reveal(value))
+                    } else {
+                        string(f, value)
+                    }
+                });
+        }
+        first = false;
+    });
+    result?;
+    f.write_char('}')
+}
+
+/// Write `source` and its sources as a JSON array, if visible.
+pub(crate) fn chain(f: &mut fmt::Formatter<'_>, source: &Source, internal: bool) -> fmt::Result {
+    let visibility = match source {
+        Source::Public(..) => "public",
+        Source::Private(..) if internal => "private",
+        _ => return f.write_str("[]"),
+    };
+    f.write_char('[')?;
+    let mut type_name = source.type_name();
+    for (i, error) in crate::Chain::new(source.any()).enumerate() {
+        if i != 0 {
+            f.write_char(',')?;
+        }
+        f.write_str("{\"type\":")?;
+        match type_name.take() {
+            Some(name) => string(f, &name)?,
+            None => f.write_str("null")?,
+        }
+        f.write_str(",\"message\":")?;
+        string(f, &error.to_string().trim_end())?;
+        f.write_str(",\"visibility\":")?;
+        string(f, &visibility)?;
+        f.write_char('}')?;
+    }
+    f.write_char(']')
+}
+
+/// Whether any of `related` is visible.
+pub(crate) fn has_related(related: &[Source], internal: bool) -> bool {
+    related
+        .iter()
+        .any(|s| internal || matches!(s, Source::Public(..)))
+}
+
+/// Write the visible `related` errors as a JSON array of chains.
+pub(crate) fn related(
+    f: &mut fmt::Formatter<'_>,
+    related: &[Source],
+    internal: bool,
+) -> fmt::Result {
+    let related = related
+        .iter()
+        .filter(|s| internal || matches!(s, Source::Public(..)));
+    f.write_char('[')?;
+    for (i, source) in related.enumerate() {
+        if i != 0 {
+            f.write_char(',')?;
+        }
+        chain(f, source, internal)?;
+    }
+    f.write_char(']')
+}
+
+/// Write `value` as a JSON string.
+fn string(f: &mut fmt::Formatter<'_>, value: &dyn fmt::Display) -> fmt::Result {
+    f.write_char('"')?;
+    write!(Escape(f), "{}", value)?;
+    f.write_char('"')
+}
+
+struct Escape<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+impl fmt::Write for Escape<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            let escaped = match c {
+                '"' => "\\\"",
+                '\\' => "\\\\",
+                '\n' => "\\n",
+                '\r' => "\\r",
+                '\t' => "\\t",
+                c if (c as u32) < 0x20 => "",
+                _ => continue,
+            };
+            self.0.write_str(&s[start..i])?;
+            if escaped.is_empty() {
+                write!(self.0, "\\u{:04x}", c as u32)?;
+            } else {
+                self.0.write_str(escaped)?;
+            }
+            start = i + c.len_utf8();
+        }
+        self.0.write_str(&s[start..])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::NoContext;
+
+    #[derive(Debug)]
+    struct Error(&'static str);
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl core::error::Error for Error {}
+
+    #[test]
+    fn escape() {
+        let status = <Status>::new("Failed to \"read\"\tfile")
+            .context_with(|c| c.insert("C:\\", "line\nbreak\u{1}"));
+        assert_eq!(
+            status.json().to_string(),
+            r#"{"kind":"Failed to \"read\"\tfile","severity":"error","context":{"C:\\":"line\nbreak\u0001"},"chain":[]}"#
+        );
+    }
+
+    #[test]
+    fn severity() {
+        let status = Status::<crate::Unkind, NoContext>::new("Deprecated key")
+            .with_severity(crate::Severity::Warning);
+        assert_eq!(
+            status.json().to_string(),
+            r#"{"kind":"Deprecated key","severity":"warning","context":{},"chain":[]}"#
+        );
+    }
+
+    #[test]
+    fn chain() {
+        let status = Status::<crate::Unkind, NoContext>::new("Failed to read file")
+            .with_internal(
+                Status::<crate::Unkind, NoContext>::new("Failed")
+                    .with_source(Error("No such file")),
+            )
+            .with_related(Error("Retried"));
+        let type_name = core::any::type_name::<Error>();
+        assert_eq!(
+            status.json().to_string(),
+            format!(
+                r#"{{"kind":"Failed to read file","severity":"error","context":{{}},"chain":[],"related":[[{{"type":"{}","message":"Retried","visibility":"public"}}]]}}"#,
+                type_name
+            )
+        );
+        assert_eq!(
+            status.into_internal().json().to_string(),
+            format!(
+                r#"{{"kind":"Failed to read file","severity":"error","context":{{}},"chain":[{{"type":"{}","message":"Failed","visibility":"private"}},{{"type":null,"message":"No such file","visibility":"private"}}],"related":[[{{"type":"{}","message":"Retried","visibility":"public"}}]]}}"#,
+                core::any::type_name::<Status<crate::Unkind, NoContext>>(),
+                type_name
+            )
+        );
+    }
+}
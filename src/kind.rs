@@ -1,4 +1,6 @@
-use std::fmt;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt;
 
 use crate::Status;
 
@@ -25,7 +27,7 @@ use crate::Status;
 ///     return ErrorKind::Read.into_err();
 /// }
 /// ```
-pub trait Kind: Copy + Clone + fmt::Display + fmt::Debug + Send + Sync + 'static {
+pub trait Kind: Clone + fmt::Display + fmt::Debug + Send + Sync + 'static {
     /// Convenience for creating an error.
     fn into_status<C: crate::Context>(self) -> Status<Self, C> {
         Status::new(self)
@@ -37,7 +39,7 @@ pub trait Kind: Copy + Clone + fmt::Display + fmt::Debug + Send + Sync + 'static
     }
 }
 
-impl<U> Kind for U where U: Copy + Clone + fmt::Display + fmt::Debug + Send + Sync + 'static {}
+impl<U> Kind for U where U: Clone + fmt::Display + fmt::Debug + Send + Sync + 'static {}
 
 /// Adhoc [`Kind`].
 ///
@@ -46,10 +48,14 @@ impl<U> Kind for U where U: Copy + Clone + fmt::Display + fmt::Debug + Send + Sy
 ///
 /// Note: This is the default [`Kind`] for [`Status`].
 ///
+/// `Unkind` is not `Copy`, since it may own its message, like when deserialized or formatted by
+/// [`status!`][crate::status!].  An `enum` holding one can't be `Copy` either.  To keep your
+/// [`Kind`] `Copy`, hold a `&'static str` instead, like in the [crate docs][crate].
+///
 /// When transitioning to a more useful [`Kind`], it could be helpful to have an `enum` variant
 /// with an `Unkind`:
 /// ```
-/// #[derive(Copy, Clone, Debug, derive_more::Display)]
+/// #[derive(Clone, Debug, derive_more::Display)]
 /// enum ErrorKind {
 ///   #[display(fmt = "Failed to read file")]
 ///   Read,
@@ -59,14 +65,24 @@ impl<U> Kind for U where U: Copy + Clone + fmt::Display + fmt::Debug + Send + Sy
 ///   Other(status::Unkind),
 /// }
 /// ```
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct Unkind {
-    inner: &'static str,
+    pub(crate) inner: Cow<'static, str>,
 }
 
 impl From<&'static str> for Unkind {
     fn from(s: &'static str) -> Self {
-        Self { inner: s }
+        Self {
+            inner: Cow::Borrowed(s),
+        }
+    }
+}
+
+impl From<String> for Unkind {
+    fn from(s: String) -> Self {
+        Self {
+            inner: Cow::Owned(s),
+        }
     }
 }
 
@@ -0,0 +1,375 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::Context;
+use crate::Kind;
+use crate::Severity;
+use crate::Status;
+
+/// Messages for one locale.
+///
+/// Catalogs use a simplified [Fluent](https://projectfluent.org/)-like syntax:
+/// ```text
+/// # Comments start with `#`
+/// Read = Impossible de lire le fichier
+/// context.Expected value = Valeur attendue
+/// ```
+/// - A [`Kind`] is looked up by its `Debug` representation, e.g. the variant name for a
+///   field-less `enum`.
+/// - A [`Context`] field is looked up by its label, prefixed with `context.`.
+/// - A [`Severity`] other than [`Severity::Error`] is looked up by its `Display`, prefixed with
+///   `severity.`, e.g. `severity.warning`.
+/// - The heading for related errors is looked up as `related`.
+/// - Indented lines continue the previous message.
+#[derive(Clone, Debug, Default)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    /// Create an empty catalog.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Parse a catalog from its source.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let catalog = status::Catalog::parse("Read = Impossible de lire le fichier").unwrap();
+    /// assert_eq!(catalog.get("Read"), Some("Impossible de lire le fichier"));
+    /// ```
+    pub fn parse(content: &str) -> Result<Self, Status> {
+        let mut catalog = Self::new();
+        let mut last: Option<String> = None;
+        for (i, line) in content.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with(char::is_whitespace) {
+                if let Some(message) = last.as_ref().and_then(|id| catalog.messages.get_mut(id)) {
+                    message.push('\n');
+                    message.push_str(trimmed);
+                    continue;
+                }
+            }
+
+            let (id, message) = match trimmed.split_once('=') {
+                Some((id, message)) if !id.trim().is_empty() => (id.trim(), message.trim()),
+                _ => {
+                    return <Status>::new("Invalid message catalog")
+                        .context_with(|c| {
+                            c.insert("Line", i + 1).insert("Expected", "`id = message`")
+                        })
+                        .into_err();
+                }
+            };
+            catalog.messages.insert(id.to_owned(), message.to_owned());
+            last = Some(id.to_owned());
+        }
+        Ok(catalog)
+    }
+
+    /// Load a catalog from a file.
+    pub fn load(path: &Path) -> Result<Self, Status> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| <Status>::new("Failed to read message catalog").with_internal(e))?;
+        Self::parse(&content)
+            .map_err(|e| e.context_with(|c| c.insert("Path", path.display().to_string())))
+    }
+
+    /// Add or replace a message.
+    pub fn insert(mut self, id: impl Into<String>, message: impl Into<String>) -> Self {
+        self.messages.insert(id.into(), message.into());
+        self
+    }
+
+    /// Look up a message.
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.messages.get(id).map(|m| m.as_str())
+    }
+}
+
+/// Render a [`Status`] from per-locale [`Catalog`]s.
+///
+/// Each message is looked up along the locale chain, e.g. `fr-CA`, `fr`, and then the default
+/// locale, before falling back to the built-in `Display`.
+///
+/// # Example
+///
+/// ```rust
+/// #[derive(Copy, Clone, Debug, derive_more::Display)]
+/// enum ErrorKind {
+///   #[display(fmt = "Failed to read file")]
+///   Read,
+/// }
+///
+/// let localizer = status::Localizer::new().with_catalog(
+///     "fr",
+///     status::Catalog::new()
+///         .insert("Read", "Impossible de lire le fichier")
+///         .insert("context.Path", "Chemin"),
+/// );
+///
+/// let status = status::Status::<ErrorKind>::new(ErrorKind::Read)
+///     .context_with(|c| c.insert("Path", "Cargo.toml"));
+/// println!("{}", localizer.localize(&status, "fr-CA"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Localizer {
+    catalogs: HashMap<String, Catalog>,
+    default_locale: Option<String>,
+}
+
+impl Localizer {
+    /// Create a `Localizer` with no catalogs.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Load each `<locale>.ftl` catalog in `dir`.
+    pub fn load_dir(dir: &Path) -> Result<Self, Status> {
+        let mut localizer = Self::new();
+        let entries = fs::read_dir(dir).map_err(|e| {
+            <Status>::new("Failed to read message catalogs")
+                .with_internal(e)
+                .context_with(|c| c.insert("Path", dir.display().to_string()))
+        })?;
+        for entry in entries {
+            let path = entry
+                .map_err(|e| <Status>::new("Failed to read message catalogs").with_internal(e))?
+                .path();
+            if path.extension().map(|e| e != "ftl").unwrap_or(true) {
+                continue;
+            }
+            let locale = match path.file_stem().and_then(|s| s.to_str()) {
+                Some(locale) => locale.to_owned(),
+                None => continue,
+            };
+            let catalog = Catalog::load(&path)?;
+            localizer = localizer.with_catalog(locale, catalog);
+        }
+        Ok(localizer)
+    }
+
+    /// Add or replace the catalog for `locale`.
+    pub fn with_catalog(mut self, locale: impl Into<String>, catalog: Catalog) -> Self {
+        self.catalogs.insert(normalize(&locale.into()), catalog);
+        self
+    }
+
+    /// Locale to try when a message is missing from the requested locale.
+    pub fn with_default_locale(mut self, locale: impl Into<String>) -> Self {
+        self.default_locale = Some(normalize(&locale.into()));
+        self
+    }
+
+    /// Render `status` for `locale`.
+    pub fn localize<'a, K: Kind, C: Context>(
+        &'a self,
+        status: &'a Status<K, C>,
+        locale: &str,
+    ) -> Localized<'a, K, C> {
+        Localized {
+            localizer: self,
+            status,
+            locales: self.chain(locale),
+        }
+    }
+
+    fn chain(&self, locale: &str) -> Vec<String> {
+        let mut locales = Vec::new();
+        let mut locale = normalize(locale);
+        while !locale.is_empty() {
+            locales.push(locale.clone());
+            let end = locale.rfind('-').unwrap_or(0);
+            locale.truncate(end);
+        }
+        if let Some(default) = &self.default_locale {
+            if !locales.contains(default) {
+                locales.push(default.clone());
+            }
+        }
+        locales
+    }
+
+    fn lookup(&self, locales: &[String], id: &str) -> Option<&str> {
+        locales
+            .iter()
+            .filter_map(|l| self.catalogs.get(l))
+            .find_map(|c| c.get(id))
+    }
+}
+
+/// A [`Status`] rendered for a locale.
+///
+/// See [`Localizer::localize`].
+#[derive(Debug)]
+pub struct Localized<'a, K: Kind, C: Context> {
+    localizer: &'a Localizer,
+    status: &'a Status<K, C>,
+    locales: Vec<String>,
+}
+
+impl<K: Kind, C: Context> fmt::Display for Localized<'_, K, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = self.status.severity();
+        if severity != Severity::Error {
+            let id = format!("severity.{}", severity);
+            match self.localizer.lookup(&self.locales, &id) {
+                Some(severity) => write!(f, "{}: ", severity)?,
+                None => write!(f, "{}: ", severity)?,
+            }
+        }
+
+        let kind = self.status.kind();
+        match self.localizer.lookup(&self.locales, &format!("{:?}", kind)) {
+            Some(message) => writeln!(f, "{}", message)?,
+            None => writeln!(f, "{}", kind)?,
+        }
+
+        let context = self.status.context();
+        if !context.is_empty() {
+            writeln!(f)?;
+            let mut visited = false;
+            let mut result = Ok(());
+            context.visit(&mut |label, value| {
+                visited = true;
+                if result.is_err() {
+                    return;
+                }
+                let id = format!("context.{}", label);
+                let label = self.localizer.lookup(&self.locales, &id).unwrap_or(label);
+                result = writeln!(f, "{}: {}", label, value);
+            });
+            result?;
+            if !visited {
+                write!(f, "{}", context)?;
+            }
+            writeln!(f)?;
+        }
+
+        let mut related = self.status.related().peekable();
+        if related.peek().is_some() {
+            if context.is_empty() {
+                writeln!(f)?;
+            }
+            let heading = self
+                .localizer
+                .lookup(&self.locales, "related")
+                .unwrap_or("Related");
+            writeln!(f, "{}:", heading)?;
+            crate::chain::render_tree(f, related)?;
+        }
+        Ok(())
+    }
+}
+
+fn normalize(locale: &str) -> String {
+    locale.replace('_', "-").to_lowercase()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, derive_more::Display)]
+    enum ErrorKind {
+        #[display(fmt = "Failed to read file")]
+        Read,
+        #[display(fmt = "Failed to parse")]
+        Parse,
+    }
+
+    fn localizer() -> Localizer {
+        let fr = Catalog::parse(
+            "
+# Errors
+Read = Impossible de lire
+  le fichier
+context.Path = Chemin
+severity.warning = Avertissement
+related = Erreurs liées
+",
+        )
+        .unwrap();
+        let fr_ca = Catalog::new().insert("Read", "Impossible de lire le fichier");
+        let de = Catalog::new().insert("Parse", "Analyse fehlgeschlagen");
+        Localizer::new()
+            .with_catalog("fr", fr)
+            .with_catalog("fr_CA", fr_ca)
+            .with_catalog("de", de)
+            .with_default_locale("de")
+    }
+
+    #[test]
+    fn parse_error() {
+        let status = Catalog::parse("Read").unwrap_err();
+        assert_eq!(
+            status.to_string(),
+            "Invalid message catalog\n\n\nLine: 1\nExpected: `id = message`\n\n"
+        );
+    }
+
+    #[test]
+    fn locale_chain() {
+        let localizer = localizer();
+        let status = <Status<ErrorKind>>::new(ErrorKind::Read)
+            .context_with(|c| c.insert("Path", "Cargo.toml"));
+        assert_eq!(
+            localizer.localize(&status, "fr-CA").to_string(),
+            "Impossible de lire le fichier\n\nChemin: Cargo.toml\n\n"
+        );
+        assert_eq!(
+            localizer.localize(&status, "fr").to_string(),
+            "Impossible de lire\nle fichier\n\nChemin: Cargo.toml\n\n"
+        );
+    }
+
+    #[test]
+    fn fallback() {
+        let localizer = localizer();
+        let status = <Status<ErrorKind>>::new(ErrorKind::Parse)
+            .context_with(|c| c.insert("Path", "Cargo.toml"));
+        assert_eq!(
+            localizer.localize(&status, "fr").to_string(),
+            "Analyse fehlgeschlagen\n\nChemin: Cargo.toml\n\n"
+        );
+        assert_eq!(
+            localizer.localize(&status, "es").to_string(),
+            "Analyse fehlgeschlagen\n\nPath: Cargo.toml\n\n"
+        );
+
+        let status = <Status<ErrorKind>>::new(ErrorKind::Read);
+        assert_eq!(
+            Localizer::new().localize(&status, "es").to_string(),
+            status.to_string()
+        );
+
+        let status = <Status<ErrorKind>>::new(ErrorKind::Read)
+            .with_severity(Severity::Note)
+            .with_related(std::io::Error::other("Retried"));
+        assert_eq!(
+            Localizer::new().localize(&status, "es").to_string(),
+            status.to_string()
+        );
+    }
+
+    #[test]
+    fn severity_related() {
+        let localizer = localizer();
+        let status = <Status<ErrorKind>>::new(ErrorKind::Read)
+            .with_severity(Severity::Warning)
+            .with_related(std::io::Error::other("Retried"))
+            .with_related_internal(std::io::Error::other("Timed out"));
+        assert_eq!(
+            localizer.localize(&status, "fr").to_string(),
+            "Avertissement: Impossible de lire\nle fichier\n\nErreurs liées:\n└─ Retried\n"
+        );
+    }
+}
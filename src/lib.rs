@@ -12,7 +12,8 @@
 //!   add.
 //! - Localizing the rendered message.
 //! - Allowing an application to make some phrasing native to its UX.
-//! - Preserving all of this while passing through FFI, IPC, and RPC (TODO [#1](https://github.com/epage/status/issues/1) [#2](https://github.com/epage/status/issues/2)).
+//! - Preserving all of this while passing through FFI, IPC, and RPC (see the `ffi` and `serde`
+//!   features).
 //!
 //! These requirements are addressed by trading off the usability of per-site custom messages with
 //! messages built up from common building blocks.  The [`Kind`] serves as a static description of the
@@ -112,7 +113,8 @@
 //! ```
 //!
 //! The same progressions happens with [`Context`], from [`AdhocContext`] to hand-written
-//! [`Context`].
+//! [`Context`].  With the `derive` feature, `#[derive(status::Context)]` can write the
+//! [`Context`] implementation for you.
 //!
 //! # FAQ
 //!
@@ -120,7 +122,8 @@
 //!
 //! For an "error" crate that wanted to focus on the programmatic use-case, the typical synonyms for
 //! "error" were too strong  because one man's error is another man's expected case.  For example,
-//! you might have a case where you need to silence some "errors" and move on.
+//! you might have a case where you need to silence some "errors" and move on.  A [`Status`] can
+//! even be a warning or note, see [`Severity`] and [`Diagnostics`].
 //!
 //! ## When should my [`Kind`] be an `enum` or an error code?
 //!
@@ -137,37 +140,101 @@
 //! - Interoperating with an ecosystem standardized on an error code system (like `HRESULT`).
 //!
 //! When using error codes, be sure to wrap them in a newtype to avoid mixing meanings.
+//!
+//! With [`KindCode`], you can have both: an `enum` with a stable [`Code`] for each variant.  The
+//! `derive` feature's `#[derive(status::Kind)]` will implement it for you.
+//!
+//! ## Can I use `status` in `no_std`?
+//!
+//! Yes, with `default-features = false`, `status` only depends on `core` and `alloc`.  Sources
+//! are stored as `core::error::Error`s.
+//!
+//! `status`'s own tests link `std`, so CI checks `no_std` by building for a target without `std`,
+//! like `cargo build --no-default-features --target thumbv7m-none-eabi`.
 
+// Tests always link `std`, see the `no_std` FAQ above.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(missing_docs)]
 #![warn(missing_debug_implementations)]
 
+extern crate alloc;
+
 mod macros;
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
 mod chain;
+mod classify;
+mod code;
+#[cfg(feature = "color")]
+mod color;
 mod context;
+mod diagnostics;
+mod exit;
 mod ext;
 mod internal;
+mod json;
 mod kind;
+#[cfg(feature = "l10n")]
+mod l10n;
+mod outcome;
+#[cfg(feature = "std")]
+mod render;
+mod report;
+#[cfg(feature = "serde")]
+mod serialize;
 mod status;
 mod term;
-
-#[cfg(not(feature = "std"))]
-compile_error!("no_std support is not implemented yet");
+#[cfg(feature = "tracing")]
+mod trace;
 
 pub use crate::chain::*;
+pub use crate::classify::*;
+pub use crate::code::*;
+#[cfg(feature = "color")]
+pub use crate::color::*;
 pub use crate::context::*;
+pub use crate::diagnostics::*;
+pub use crate::exit::*;
 pub use crate::ext::*;
 pub use crate::internal::*;
+pub use crate::json::*;
 pub use crate::kind::*;
+#[cfg(feature = "l10n")]
+pub use crate::l10n::*;
+#[doc(hidden)]
+pub use crate::macros::__operand;
+#[doc(hidden)]
+pub use crate::macros::__status;
+pub use crate::outcome::*;
+#[cfg(feature = "std")]
+pub use crate::render::*;
+pub use crate::report::*;
+#[cfg(feature = "serde")]
+pub use crate::serialize::*;
 pub use crate::status::*;
 pub use crate::term::*;
 
-pub(crate) type StdError = dyn std::error::Error + 'static;
+#[cfg(feature = "derive")]
+pub use status_derive::*;
+
+pub(crate) type StdError = dyn core::error::Error + 'static;
 
 // Is there a case for having `send_sync` off?
 // If not, we should probably add the wrapper from failure,
 // https://github.com/rust-lang-nursery/failure/blob/master/src/sync_failure.rs
 #[cfg(feature = "send_sync")]
-pub(crate) type StrictError = dyn std::error::Error + Send + Sync + 'static;
+pub(crate) type StrictError = dyn core::error::Error + Send + Sync + 'static;
+#[cfg(not(feature = "send_sync"))]
+pub(crate) type StrictError = dyn core::error::Error + 'static;
+
+/// Errors that can be held as a [`StrictError`], for bounding generic impls once.
+#[cfg(feature = "send_sync")]
+pub(crate) trait SourceError: core::error::Error + Send + Sync + 'static {}
+#[cfg(feature = "send_sync")]
+impl<E: core::error::Error + Send + Sync + 'static> SourceError for E {}
+#[cfg(not(feature = "send_sync"))]
+pub(crate) trait SourceError: core::error::Error + 'static {}
 #[cfg(not(feature = "send_sync"))]
-pub(crate) type StrictError = dyn std::error::Error + 'static;
+impl<E: core::error::Error + 'static> SourceError for E {}
@@ -1,6 +1,130 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+use crate::Context;
+use crate::Kind;
+use crate::Status;
+
+/// Create a [`Status`][crate::Status].
+///
+/// The first argument is the [`Kind`][crate::Kind], followed by any of:
+/// - `source = error`: see [`Status::with_source`][crate::Status::with_source].
+/// - `internal = error`: see [`Status::with_internal`][crate::Status::with_internal].
+/// - `related = error`: see [`Status::with_related`][crate::Status::with_related].
+/// - `severity = severity`: see [`Status::with_severity`][crate::Status::with_severity].
+/// - `context = context`: merged with [`Context::update`][crate::Context::update].
+/// - `"key" => value`: inserted into an [`AdhocContext`][crate::AdhocContext].
+///
+/// When the [`Kind`][crate::Kind] is a string literal, it is formatted like `format!`, with inline
+/// captures or positional arguments following it.  Without anything to format, the `&'static str`
+/// is used as-is.  Named arguments, like `x = 5`, are not supported.  Context keys after format
+/// arguments must be a single token, like `"key"` or `KEY`.
+///
+/// The [`Kind`][crate::Kind] and [`Context`][crate::Context] are inferred, so this works with your
+/// own `Status` alias.
+///
+/// # Example
+///
+/// ```
+/// # use std::io;
+/// let error = io::Error::new(io::ErrorKind::NotFound, "No such file");
+/// let status: status::Status = status::status!(
+///     "Failed to read file",
+///     source = error,
+///     "Path" => "Cargo.toml",
+///     "Expected" => 5,
+/// );
+/// assert_eq!(status.get::<i32>("Expected"), Some(&5));
+/// ```
+///
+/// ```
+/// let path = "Cargo.toml";
+/// let status: status::Status = status::status!("Failed to read {path}", "Line" => 3);
+/// assert_eq!(status.kind().to_string(), "Failed to read Cargo.toml\n");
+/// ```
+///
+/// ```compile_fail
+/// let status: status::Status = status::status!("Failed to read {path}", path = "Cargo.toml");
+/// ```
+#[macro_export]
+macro_rules! status {
+    ($fmt:literal $(, $($rest:tt)*)?) => {
+        $crate::__status_format!(($fmt) $(, $($rest)*)?)
+    };
+    ($kind:expr $(, $($rest:tt)*)?) => {{
+        #[allow(unused_mut)]
+        let mut status = $crate::Status::new($kind);
+        $($crate::__status_with!(status, $($rest)*);)?
+        status
+    }};
+}
+
+/// Split the format arguments from the options for [`status!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __status_format {
+    (@status ($($head:tt)*) $($rest:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut status = $crate::__status(::core::format_args!($($head)*));
+        $crate::__status_with!(status $($rest)*);
+        status
+    }};
+    (($($head:tt)*) $(,)?) => {
+        $crate::__status_format!(@status ($($head)*))
+    };
+    (($($head:tt)*), $option:ident = $($rest:tt)*) => {
+        $crate::__status_format!(@status ($($head)*), $option = $($rest)*)
+    };
+    (($($head:tt)*), $key:tt => $($rest:tt)*) => {
+        $crate::__status_format!(@status ($($head)*), $key => $($rest)*)
+    };
+    (($($head:tt)*), $arg:expr $(, $($rest:tt)*)?) => {
+        $crate::__status_format!(($($head)*, $arg) $(, $($rest)*)?)
+    };
+}
+
+/// Apply the options for [`status!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __status_with {
+    ($status:ident $(,)?) => {};
+    ($status:ident, source = $error:expr $(, $($rest:tt)*)?) => {
+        $status = $crate::Status::with_source($status, $error);
+        $($crate::__status_with!($status, $($rest)*);)?
+    };
+    ($status:ident, internal = $error:expr $(, $($rest:tt)*)?) => {
+        $status = $crate::Status::with_internal($status, $error);
+        $($crate::__status_with!($status, $($rest)*);)?
+    };
+    ($status:ident, related = $error:expr $(, $($rest:tt)*)?) => {
+        $status = $crate::Status::with_related($status, $error);
+        $($crate::__status_with!($status, $($rest)*);)?
+    };
+    ($status:ident, severity = $severity:expr $(, $($rest:tt)*)?) => {
+        $status = $crate::Status::with_severity($status, $severity);
+        $($crate::__status_with!($status, $($rest)*);)?
+    };
+    ($status:ident, context = $context:expr $(, $($rest:tt)*)?) => {
+        *$status = $crate::Context::update(::core::mem::take(&mut *$status), $context);
+        $($crate::__status_with!($status, $($rest)*);)?
+    };
+    ($status:ident, $option:ident = $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown `status!` option `",
+            ::core::stringify!($option),
+            "`, named format arguments are not supported, use inline captures instead"
+        ));
+    };
+    ($status:ident, $key:expr => $value:expr $(, $($rest:tt)*)?) => {
+        *$status = $crate::AdhocContext::insert(::core::mem::take(&mut *$status), $key, $value);
+        $($crate::__status_with!($status, $($rest)*);)?
+    };
+}
+
 /// Return early with an error.
 ///
-/// This macro is equivalent to `return Err(From::from($err))`.
+/// This macro is equivalent to `return Err(status!(...))`, see [`status!`] for the arguments.
 ///
 /// # Example
 ///
@@ -35,19 +159,27 @@
 ///     status::bail!(ErrorKind::Read);
 /// }
 /// ```
+///
+/// ```
+/// # fn read_file(path: &str) -> Result<String, status::Status> {
+/// let content = match std::fs::read_to_string(path) {
+///     Ok(content) => content,
+///     Err(e) => status::bail!("Failed to read file", internal = e, "Path" => path.to_owned()),
+/// };
+/// #     Ok(content)
+/// # }
+/// ```
 #[macro_export]
 macro_rules! bail {
-    ($msg:literal $(,)?) => {
-        return ::core::result::Result::Err($crate::Status::new($msg));
-    };
-    ($err:expr $(,)?) => {
-        return ::core::result::Result::Err($crate::Status::new($err));
+    ($($args:tt)+) => {
+        return ::core::result::Result::Err($crate::status!($($args)+))
     };
 }
 
 /// Return early with an error if a condition is not satisfied.
 ///
-/// This macro is equivalent to `if !$cond { return Err(From::from($err)); }`.
+/// This macro is equivalent to `if !$cond { return Err(status!(...)); }`, see [`status!`] for
+/// the arguments.
 ///
 /// Analogously to `assert!`, `ensure!` takes a condition and exits the function
 /// if the condition fails. Unlike `assert!`, `ensure!` returns an `Error`
@@ -86,14 +218,326 @@ macro_rules! bail {
 /// ```
 #[macro_export]
 macro_rules! ensure {
-    ($cond:expr, $msg:literal $(,)?) => {
+    ($cond:expr, $($args:tt)+) => {
         if !$cond {
-            return ::core::result::Result::Err($crate::Status::new($msg));
+            return ::core::result::Result::Err($crate::status!($($args)+));
         }
     };
-    ($cond:expr, $err:expr $(,)?) => {
-        if !$cond {
-            return ::core::result::Result::Err($crate::Status::new($err));
+}
+
+/// Return early with an error if two expressions are not equal.
+///
+/// Analogously to `assert_eq!`, the [`AdhocContext`][crate::AdhocContext] gets `"Left"` and
+/// `"Right"`, each as the source text and the `Debug` of its value.  See [`status!`] for the
+/// remaining arguments.
+///
+/// # Example
+///
+/// ```
+/// fn check_version(version: u32) -> Result<(), status::Status> {
+///     status::ensure_eq!(version, 2, "Unsupported version");
+///     Ok(())
+/// }
+///
+/// let status = check_version(1).unwrap_err();
+/// assert_eq!(status.get::<String>("Left").unwrap(), "version = 1");
+/// assert_eq!(status.get::<String>("Right").unwrap(), "2 = 2");
+/// ```
+#[macro_export]
+macro_rules! ensure_eq {
+    ($left:expr, $right:expr, $($args:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    #[allow(unused_mut)]
+                    let mut status = $crate::status!($($args)+);
+                    $crate::__status_with!(
+                        status,
+                        "Left" => $crate::__operand(::core::stringify!($left), left),
+                        "Right" => $crate::__operand(::core::stringify!($right), right),
+                    );
+                    return ::core::result::Result::Err(status);
+                }
+            }
         }
     };
 }
+
+/// Return early with an error if two expressions are equal.
+///
+/// See [`ensure_eq!`].
+#[macro_export]
+macro_rules! ensure_ne {
+    ($left:expr, $right:expr, $($args:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left == *right {
+                    #[allow(unused_mut)]
+                    let mut status = $crate::status!($($args)+);
+                    $crate::__status_with!(
+                        status,
+                        "Left" => $crate::__operand(::core::stringify!($left), left),
+                        "Right" => $crate::__operand(::core::stringify!($right), right),
+                    );
+                    return ::core::result::Result::Err(status);
+                }
+            }
+        }
+    };
+}
+
+/// Return early with an error if an expression does not match a pattern.
+///
+/// Analogously to `assert!(matches!(..))`, the [`AdhocContext`][crate::AdhocContext] gets
+/// `"Value"`, as the source text and the `Debug` of the value, and `"Expected"`, as the pattern.
+/// See [`status!`] for the remaining arguments.
+///
+/// # Example
+///
+/// ```
+/// fn check_port(port: Option<u16>) -> Result<(), status::Status> {
+///     status::ensure_matches!(port, Some(1024..=65535), "Invalid port");
+///     Ok(())
+/// }
+///
+/// let status = check_port(Some(80)).unwrap_err();
+/// assert_eq!(status.get::<String>("Value").unwrap(), "port = Some(80)");
+/// assert_eq!(status.get::<&str>("Expected").unwrap(), &"Some(1024..=65535)");
+/// ```
+#[macro_export]
+macro_rules! ensure_matches {
+    ($value:expr, $($pattern:pat)|+ $(if $guard:expr)?, $($args:tt)+) => {
+        match &$value {
+            value => {
+                if !::core::matches!(value, $($pattern)|+ $(if $guard)?) {
+                    #[allow(unused_mut)]
+                    let mut status = $crate::status!($($args)+);
+                    $crate::__status_with!(
+                        status,
+                        "Value" => $crate::__operand(::core::stringify!($value), value),
+                        "Expected" => ::core::stringify!($($pattern)|+ $(if $guard)?),
+                    );
+                    return ::core::result::Result::Err(status);
+                }
+            }
+        }
+    };
+}
+
+/// Create the [`Status`] for a [`status!`] format string, only allocating when there is something
+/// to format.
+#[doc(hidden)]
+pub fn __status<K, C>(args: fmt::Arguments<'_>) -> Status<K, C>
+where
+    K: Kind + From<&'static str> + From<String>,
+    C: Context,
+{
+    match args.as_str() {
+        Some(message) => Status::new(message),
+        None => Status::new(format!("{}", args)),
+    }
+}
+
+/// Render an operand for the `ensure_*!` macros.
+#[doc(hidden)]
+pub fn __operand(source: &'static str, value: &dyn fmt::Debug) -> String {
+    format!("{} = {:?}", source, value)
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt;
+    use std::io;
+
+    use crate::Context;
+    use crate::Severity;
+    use crate::Status;
+
+    #[derive(Copy, Clone, Debug, PartialEq, derive_more::Display)]
+    enum ErrorKind {
+        #[display(fmt = "Failed to read file")]
+        Read,
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct ReadContext {
+        line: Option<usize>,
+    }
+
+    impl fmt::Display for ReadContext {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if let Some(line) = self.line {
+                writeln!(f, "Line: {}", line)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl Context for ReadContext {
+        fn update(self, replacements: Self) -> Self {
+            Self {
+                line: replacements.line.or(self.line),
+            }
+        }
+
+        fn is_empty(&self) -> bool {
+            self.line.is_none()
+        }
+    }
+
+    type ReadStatus = Status<ErrorKind, ReadContext>;
+
+    fn read(fail: bool) -> Result<(), ReadStatus> {
+        crate::ensure!(
+            !fail,
+            ErrorKind::Read,
+            internal = io::Error::other("Truncated"),
+            severity = Severity::Warning,
+            context = ReadContext { line: Some(3) },
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn custom_alias() {
+        assert!(read(false).is_ok());
+        let status = read(true).unwrap_err();
+        assert_eq!(status.kind(), ErrorKind::Read);
+        assert_eq!(status.line, Some(3));
+        assert_eq!(status.severity(), Severity::Warning);
+        assert_eq!(status.into_internal().sources().count(), 1);
+    }
+
+    #[test]
+    fn adhoc() {
+        fn read() -> Result<(), Status> {
+            crate::bail!(
+                "Failed to read file",
+                source = io::Error::other("Truncated"),
+                related = io::Error::other("Retried"),
+                "Path" => "Cargo.toml",
+                "Line" => 3,
+            )
+        }
+
+        let status = read().unwrap_err();
+        assert_eq!(status.sources().count(), 1);
+        assert_eq!(status.related().count(), 1);
+        assert_eq!(status.get::<&str>("Path"), Some(&"Cargo.toml"));
+        assert_eq!(status.get::<i32>("Line"), Some(&3));
+    }
+
+    #[test]
+    fn format_args() {
+        fn read(path: &str, line: usize) -> Result<(), Status> {
+            crate::ensure!(
+                line == 0,
+                "Failed to read {} at line {}",
+                path,
+                line + 1,
+                internal = io::Error::other("Truncated"),
+                "Path" => path.to_owned(),
+            );
+            Ok(())
+        }
+
+        let status = read("Cargo.toml", 2).unwrap_err();
+        assert_eq!(
+            status.kind().to_string(),
+            "Failed to read Cargo.toml at line 3\n"
+        );
+        assert_eq!(
+            status.get::<String>("Path").map(|s| s.as_str()),
+            Some("Cargo.toml")
+        );
+        assert_eq!(status.into_internal().sources().count(), 1);
+
+        let name = "data";
+        let status: Status = crate::status!("Missing {}", name,);
+        assert_eq!(status.kind().to_string(), "Missing data\n");
+    }
+
+    #[test]
+    fn format_inline() {
+        let path = "Cargo.toml";
+        let status: Status = crate::status!("Failed to read {path}", "Line" => 3);
+        assert_eq!(status.kind().to_string(), "Failed to read Cargo.toml\n");
+        assert_eq!(status.get::<i32>("Line"), Some(&3));
+
+        let status: Status = crate::status!("Failed to read {path:?}");
+        assert_eq!(status.kind().to_string(), "Failed to read \"Cargo.toml\"\n");
+
+        let status: Status = crate::status!("Failed to read {{path}}");
+        assert_eq!(status.kind().to_string(), "Failed to read {path}\n");
+    }
+
+    #[test]
+    fn ensure_eq() {
+        fn check(left: &[u8], right: &[u8]) -> Result<(), Status> {
+            crate::ensure_eq!(left.len(), right.len(), "Lengths differ", "Name" => "data",);
+            Ok(())
+        }
+
+        assert!(check(b"a", b"b").is_ok());
+        let status = check(b"a", b"bc").unwrap_err();
+        assert_eq!(status.get::<&str>("Name"), Some(&"data"));
+        assert_eq!(
+            status.get::<String>("Left").map(|s| s.as_str()),
+            Some("left.len() = 1")
+        );
+        assert_eq!(
+            status.get::<String>("Right").map(|s| s.as_str()),
+            Some("right.len() = 2")
+        );
+    }
+
+    #[test]
+    fn ensure_ne() {
+        fn check(left: &[u8], right: &[u8]) -> Result<(), Status> {
+            crate::ensure_ne!(left, right, "Values are the same");
+            Ok(())
+        }
+
+        assert!(check(b"a", b"b").is_ok());
+        let status = check(b"a", b"a").unwrap_err();
+        assert_eq!(status.kind().to_string().trim_end(), "Values are the same");
+        assert_eq!(
+            status.get::<String>("Left").map(|s| s.as_str()),
+            Some("left = [97]")
+        );
+    }
+
+    #[test]
+    fn ensure_matches() {
+        fn check(value: Option<i32>) -> Result<(), Status<ErrorKind>> {
+            crate::ensure_matches!(value, Some(1) | Some(2) if true, ErrorKind::Read);
+            Ok(())
+        }
+
+        assert!(check(Some(2)).is_ok());
+        let status = check(None).unwrap_err();
+        assert_eq!(
+            status.get::<String>("Value").map(|s| s.as_str()),
+            Some("value = None")
+        );
+        assert_eq!(
+            status.get::<&str>("Expected"),
+            Some(&"Some(1) | Some(2) if true")
+        );
+    }
+    #[test]
+    fn ensure_matches_binding() {
+        fn check(name: Option<String>) -> Result<(), Status> {
+            crate::ensure_matches!(name, Some(s) if !s.is_empty(), "Missing name");
+            Ok(())
+        }
+
+        assert!(check(Some("data".to_owned())).is_ok());
+        let status = check(Some(String::new())).unwrap_err();
+        assert_eq!(
+            status.get::<String>("Value").map(|s| s.as_str()),
+            Some(r#"name = Some("")"#)
+        );
+        assert!(check(None).is_err());
+    }
+}
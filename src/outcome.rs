@@ -0,0 +1,214 @@
+use alloc::vec::Vec;
+
+use crate::AdhocContext;
+use crate::Context;
+use crate::Kind;
+use crate::Severity;
+use crate::Status;
+use crate::Unkind;
+
+/// A value from an operation that succeeded, along with any warnings it reported.
+///
+/// # Example
+///
+/// ```rust
+/// use status::Outcome;
+/// use status::WarningPolicy;
+/// type Status = status::Status;
+///
+/// fn load_key(key: &str) -> Result<Outcome<String>, Status> {
+///     let mut outcome = Outcome::new(key.to_uppercase());
+///     if key == "color" {
+///         let status = Status::new("Deprecated key");
+///         outcome.warn(status.context_with(|c| c.insert("Key", key.to_owned())));
+///     }
+///     Ok(outcome)
+/// }
+///
+/// fn load_config() -> Result<Outcome<Vec<String>>, Status> {
+///     let mut outcome = Outcome::new(Vec::new());
+///     for key in ["name", "color"] {
+///         let value = outcome.absorb(load_key(key)?);
+///         outcome.value_mut().push(value);
+///     }
+///     Ok(outcome)
+/// }
+///
+/// let outcome = load_config().unwrap();
+/// assert_eq!(outcome.warnings().len(), 1);
+/// assert!(outcome.into_result(WarningPolicy::Deny).is_err());
+/// ```
+#[derive(Debug)]
+pub struct Outcome<T, K: Kind = Unkind, C: Context = AdhocContext> {
+    value: T,
+    warnings: Vec<Status<K, C>>,
+}
+
+impl<T, K: Kind, C: Context> Outcome<T, K, C> {
+    /// Succeed with `value`, without any warnings.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Add a warning.
+    ///
+    /// A [`Severity::Error`] is lowered to [`Severity::Warning`].
+    pub fn with_warning(mut self, status: Status<K, C>) -> Self {
+        self.warn(status);
+        self
+    }
+
+    /// Add a warning.
+    ///
+    /// A [`Severity::Error`] is lowered to [`Severity::Warning`].
+    pub fn warn(&mut self, status: Status<K, C>) {
+        let status = if status.severity().is_error() {
+            status.with_severity(Severity::Warning)
+        } else {
+            status
+        };
+        self.warnings.push(status);
+    }
+
+    /// Take the warnings from `other`, returning its value.
+    ///
+    /// Combined with `?` on a `Result<Outcome<_>, Status>`, this propagates warnings up the stack.
+    pub fn absorb<U>(&mut self, other: Outcome<U, K, C>) -> U {
+        self.warnings.extend(other.warnings);
+        other.value
+    }
+
+    /// Access the value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Modify the value.
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// The warnings, in the order they were reported.
+    pub fn warnings(&self) -> &[Status<K, C>] {
+        &self.warnings
+    }
+
+    /// Whether any warnings were reported.
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+
+    /// Convert the value, keeping the warnings.
+    pub fn map<U, F>(self, f: F) -> Outcome<U, K, C>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Outcome {
+            value: f(self.value),
+            warnings: self.warnings,
+        }
+    }
+
+    /// Continue with another operation, merging its warnings after these.
+    pub fn and_then<U, F>(self, f: F) -> Result<Outcome<U, K, C>, Status<K, C>>
+    where
+        F: FnOnce(T) -> Result<Outcome<U, K, C>, Status<K, C>>,
+    {
+        let mut next = f(self.value)?;
+        let mut warnings = self.warnings;
+        warnings.append(&mut next.warnings);
+        next.warnings = warnings;
+        Ok(next)
+    }
+
+    /// Split into the value and the warnings.
+    pub fn into_parts(self) -> (T, Vec<Status<K, C>>) {
+        (self.value, self.warnings)
+    }
+
+    /// Decide whether the warnings are errors.
+    ///
+    /// With [`WarningPolicy::Deny`], the first warning becomes the error, as a
+    /// [`Severity::Error`], with any other warnings related to it.
+    pub fn into_result(self, policy: WarningPolicy) -> Result<T, Status<K, C>> {
+        match policy {
+            WarningPolicy::Allow => Ok(self.value),
+            WarningPolicy::Deny => {
+                let mut warnings = self.warnings.into_iter();
+                match warnings.next() {
+                    Some(first) => Err(warnings
+                        .fold(first.with_severity(Severity::Error), |status, warning| {
+                            status.with_related(warning)
+                        })),
+                    None => Ok(self.value),
+                }
+            }
+        }
+    }
+}
+
+impl<T, K: Kind, C: Context> From<T> for Outcome<T, K, C> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Whether an [`Outcome`]'s warnings are errors, see [`Outcome::into_result`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum WarningPolicy {
+    /// Ignore warnings.
+    #[default]
+    Allow,
+    /// Fail on any warning.
+    Deny,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn step(value: u32, warning: Option<&'static str>) -> Result<Outcome<u32>, Status> {
+        let outcome = Outcome::new(value);
+        Ok(match warning {
+            Some(warning) => outcome.with_warning(<Status>::new(warning)),
+            None => outcome,
+        })
+    }
+
+    #[test]
+    fn and_then() {
+        let outcome = step(1, Some("Skipped file"))
+            .and_then(|o| o.and_then(|v| step(v + 1, Some("Deprecated key"))))
+            .unwrap();
+        assert_eq!(*outcome.value(), 2);
+        let kinds: Vec<_> = outcome
+            .warnings()
+            .iter()
+            .map(|s| s.kind().to_string().trim_end().to_owned())
+            .collect();
+        assert_eq!(kinds, ["Skipped file", "Deprecated key"]);
+        assert!(outcome
+            .warnings()
+            .iter()
+            .all(|s| s.severity() == Severity::Warning));
+    }
+
+    #[test]
+    fn into_result() {
+        let outcome = step(1, None).unwrap();
+        assert_eq!(outcome.into_result(WarningPolicy::Deny).unwrap(), 1);
+
+        let outcome = step(1, Some("Skipped file"))
+            .unwrap()
+            .with_warning(<Status>::new("Deprecated key"));
+        let status = outcome.into_result(WarningPolicy::Deny).unwrap_err();
+        assert_eq!(status.severity(), Severity::Error);
+        assert_eq!(status.related().count(), 1);
+
+        let outcome = step(1, Some("Skipped file")).unwrap();
+        assert_eq!(outcome.into_result(WarningPolicy::Allow).unwrap(), 1);
+    }
+}
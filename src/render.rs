@@ -0,0 +1,859 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::str;
+use std::sync::OnceLock;
+
+use crate::context::Reveal;
+use crate::context::REDACTED;
+use crate::Report;
+use crate::Status;
+
+static RENDERER: OnceLock<Box<dyn Renderer>> = OnceLock::new();
+
+/// Render a [`Report`] for the user.
+///
+/// For the built-in formats, see [`TextRenderer`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::fmt;
+/// use status::Renderer;
+/// use status::Report;
+///
+/// #[derive(Debug)]
+/// struct Shouting;
+///
+/// impl Renderer for Shouting {
+///     fn render(&self, f: &mut fmt::Formatter<'_>, report: &dyn Report) -> fmt::Result {
+///         write!(f, "{}!", report.to_string().trim_end().to_uppercase())
+///     }
+/// }
+///
+/// let status = <status::Status>::new("Failed to read file");
+/// assert_eq!(Shouting.display(&status).to_string(), "FAILED TO READ FILE!");
+/// ```
+pub trait Renderer: fmt::Debug + Send + Sync {
+    /// Write `report` to `f`.
+    fn render(&self, f: &mut fmt::Formatter<'_>, report: &dyn Report) -> fmt::Result;
+
+    /// Adapt `report` for `{}` formatting with this renderer.
+    fn display<'a>(&'a self, report: &'a dyn Report) -> Rendered<'a>
+    where
+        Self: Sized,
+    {
+        Rendered {
+            renderer: self,
+            report,
+        }
+    }
+}
+
+/// A [`Report`] adapted for `{}` formatting with a [`Renderer`].
+///
+/// See [`render`] and [`Renderer::display`].
+#[derive(Debug)]
+pub struct Rendered<'a> {
+    renderer: &'a dyn Renderer,
+    report: &'a dyn Report,
+}
+
+impl fmt::Display for Rendered<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.renderer.render(f, self.report)
+    }
+}
+
+/// Set the [`Renderer`] used by [`render`] and [`TerminatingStatus`][crate::TerminatingStatus].
+///
+/// This can only be set once, before the first call to [`renderer`].  On failure, the rejected
+/// `renderer` is returned.
+pub fn set_renderer(renderer: impl Renderer + 'static) -> Result<(), Box<dyn Renderer>> {
+    RENDERER.set(Box::new(renderer))
+}
+
+/// The [`Renderer`] from [`set_renderer`], defaulting to [`TextRenderer::from_env`].
+pub fn renderer() -> &'static dyn Renderer {
+    RENDERER
+        .get_or_init(|| Box::new(TextRenderer::from_env()))
+        .as_ref()
+}
+
+/// Render `report` with the global [`renderer`].
+///
+/// # Example
+///
+/// ```rust
+/// let status = <status::Status>::new("Failed to read file");
+/// eprintln!("{}", status::render(&status));
+/// ```
+pub fn render(report: &dyn Report) -> Rendered<'_> {
+    Rendered {
+        renderer: renderer(),
+        report,
+    }
+}
+
+/// Layouts for [`TextRenderer`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Format {
+    /// The kind, context, related errors, and public sources, one section after another.
+    #[default]
+    Plain,
+    /// Like [`Format::Plain`] but including private sources and the backtrace, see
+    /// [`InternalStatus`][crate::InternalStatus].
+    Verbose,
+    /// Everything on a single line, for logs.
+    Compact,
+    /// The kind and context, followed by the sources and related errors as a tree.
+    Tree,
+}
+
+/// Parses `plain`, `verbose`, `compact`, or `tree`.
+impl str::FromStr for Format {
+    type Err = Status;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "plain" => Ok(Self::Plain),
+            "verbose" => Ok(Self::Verbose),
+            "compact" => Ok(Self::Compact),
+            "tree" => Ok(Self::Tree),
+            _ => <Status>::new("Unknown status format")
+                .context_with(|c| {
+                    c.insert("Format", s.to_owned())
+                        .insert("Expected", "plain, verbose, compact, or tree")
+                })
+                .into_err(),
+        }
+    }
+}
+
+/// Built-in [`Renderer`] for text.
+///
+/// Duplicated cause messages, like from an error that includes its source in its own message,
+/// are only shown once.
+///
+/// # Example
+///
+/// ```rust
+/// use status::Format;
+/// use status::Renderer;
+/// use status::TextRenderer;
+///
+/// let status = <status::Status>::new("Failed to read file")
+///     .context_with(|c| c.insert("Path", "Cargo.toml"));
+/// let renderer = TextRenderer::new(Format::Compact);
+/// assert_eq!(
+///     renderer.display(&status).to_string(),
+///     "Failed to read file (Path: Cargo.toml)"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TextRenderer {
+    format: Format,
+    width: Option<usize>,
+    max_value_len: Option<usize>,
+    redaction: Redaction,
+    #[cfg(feature = "color")]
+    color: bool,
+}
+
+impl TextRenderer {
+    /// Render with `format`.
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            ..Default::default()
+        }
+    }
+
+    /// Configure from the environment, for rendering to `stderr`.
+    ///
+    /// - `STATUS_FORMAT`: the [`Format`], defaulting to [`Format::Plain`].
+    /// - `COLUMNS`: the width to wrap at.
+    /// - With the `color` feature, see [`ColorChoice::Auto`][crate::ColorChoice::Auto].
+    pub fn from_env() -> Self {
+        let format = env::var("STATUS_FORMAT")
+            .ok()
+            .and_then(|f| f.parse().ok())
+            .unwrap_or_default();
+        let renderer =
+            Self::new(format).with_width(env::var("COLUMNS").ok().and_then(|c| c.parse().ok()));
+        #[cfg(feature = "color")]
+        let renderer = renderer.with_color(crate::ColorChoice::Auto.resolve(&std::io::stderr()));
+        renderer
+    }
+
+    /// Wrap lines at `width` characters.
+    ///
+    /// Only [`Format::Plain`] and [`Format::Verbose`] are wrapped.
+    pub fn with_width(mut self, width: Option<usize>) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Truncate context values longer than `len` characters.
+    pub fn with_max_value_len(mut self, len: Option<usize>) -> Self {
+        self.max_value_len = len;
+        self
+    }
+
+    /// Hide context values whose keys match `redaction`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use status::Format;
+    /// use status::Redaction;
+    /// use status::Renderer;
+    /// use status::TextRenderer;
+    ///
+    /// let status = <status::Status>::new("Failed to log in")
+    ///     .context_with(|c| c.insert("User", "epage").insert("API Token", "hunter2"));
+    /// let renderer = TextRenderer::new(Format::Compact)
+    ///     .with_redaction(Redaction::new().with_pattern("*token*"));
+    /// assert_eq!(
+    ///     renderer.display(&status).to_string(),
+    ///     "Failed to log in (User: epage, API Token: [redacted])"
+    /// );
+    /// ```
+    pub fn with_redaction(mut self, redaction: Redaction) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Whether to style the output.
+    #[cfg(feature = "color")]
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub(crate) fn write<E: Report + ?Sized>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        report: &E,
+    ) -> fmt::Result {
+        match self.format {
+            Format::Plain => self.write_text(f, report, false),
+            Format::Verbose => self.write_text(f, report, true),
+            Format::Compact => self.write_compact(f, report),
+            Format::Tree => self.write_tree(f, report),
+        }
+    }
+
+    fn write_text<E: Report + ?Sized>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        report: &E,
+        internal: bool,
+    ) -> fmt::Result {
+        let headline = headline(report);
+        for line in headline.lines().flat_map(|l| self.wrap(l, 0, 0)) {
+            self.paint(f, Role::Kind, &line)?;
+            writeln!(f)?;
+        }
+
+        let fields = self.fields(report, internal);
+        if !fields.is_empty() {
+            writeln!(f)?;
+            for field in fields {
+                self.write_field(f, &field)?;
+            }
+        }
+
+        let related = if internal {
+            report.internal_related()
+        } else {
+            report.related()
+        };
+        let mut related = related.peekable();
+        if related.peek().is_some() {
+            writeln!(f)?;
+            self.paint(f, Role::Heading, "Related:")?;
+            writeln!(f)?;
+            crate::chain::render_tree(f, related)?;
+        }
+
+        let source = if internal {
+            report.internal_source()
+        } else {
+            report.source()
+        };
+        let causes = causes(&headline, source);
+        if self.is_styled() && !causes.is_empty() {
+            writeln!(f)?;
+            self.paint(f, Role::Heading, "Caused by:")?;
+            writeln!(f)?;
+            for cause in causes {
+                for line in cause.lines() {
+                    for line in self.wrap(line, INDENT.len(), INDENT.len()) {
+                        writeln!(f, "{}{}", INDENT, line)?;
+                    }
+                }
+            }
+        } else {
+            for cause in causes {
+                writeln!(f)?;
+                f.write_str(CAUSED_BY)?;
+                self.write_value(f, &cause, CAUSED_BY.len())?;
+            }
+        }
+
+        let backtrace = if internal {
+            report.internal_backtrace()
+        } else {
+            report.backtrace()
+        };
+        if let Some(backtrace) = backtrace {
+            writeln!(f)?;
+            self.paint(f, Role::Heading, "Backtrace:")?;
+            writeln!(f)?;
+            writeln!(f, "{}", backtrace)?;
+        }
+        Ok(())
+    }
+
+    fn write_field(&self, f: &mut fmt::Formatter<'_>, field: &Field) -> fmt::Result {
+        match &field.label {
+            Some(label) => {
+                self.paint(f, Role::Key, &format!("{}:", label))?;
+                f.write_str(" ")?;
+                self.write_value(f, &field.value, label.chars().count() + 2)
+            }
+            None => self.write_value(f, &field.value, 0),
+        }
+    }
+
+    /// Write `value` after `indent` columns already used, aligning lines wrapped from the first
+    /// line to it.
+    ///
+    /// Lines after the first are written as-is, like `Display` would.
+    fn write_value(&self, f: &mut fmt::Formatter<'_>, value: &str, indent: usize) -> fmt::Result {
+        if value.is_empty() {
+            return writeln!(f);
+        }
+        for (i, line) in value.lines().enumerate() {
+            let (first, rest) = if i == 0 { (indent, indent) } else { (0, 0) };
+            for (j, line) in self.wrap(line, first, rest).into_iter().enumerate() {
+                if j != 0 {
+                    write!(f, "{:rest$}", "", rest = rest)?;
+                }
+                writeln!(f, "{}", line)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_compact<E: Report + ?Sized>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        report: &E,
+    ) -> fmt::Result {
+        let headline = headline(report);
+        self.paint(f, Role::Kind, &single_line(&headline))?;
+
+        let fields = self.fields(report, false);
+        if !fields.is_empty() {
+            f.write_str(" (")?;
+            for (i, field) in fields.iter().enumerate() {
+                if i != 0 {
+                    f.write_str(", ")?;
+                }
+                if let Some(label) = &field.label {
+                    self.paint(f, Role::Key, &format!("{}:", label))?;
+                    f.write_str(" ")?;
+                }
+                f.write_str(&single_line(&field.value))?;
+            }
+            f.write_str(")")?;
+        }
+
+        for cause in causes(&headline, report.source()) {
+            write!(f, ": {}", single_line(&cause))?;
+        }
+
+        let related = report.related().count();
+        if related != 0 {
+            write!(f, " (+{} related)", related)?;
+        }
+        Ok(())
+    }
+
+    fn write_tree<E: Report + ?Sized>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        report: &E,
+    ) -> fmt::Result {
+        self.paint(f, Role::Kind, &headline(report))?;
+        writeln!(f)?;
+        for field in self.fields(report, false) {
+            if let Some(label) = &field.label {
+                self.paint(f, Role::Key, &format!("{}:", label))?;
+                f.write_str(" ")?;
+            }
+            writeln!(f, "{}", field.value.trim_end())?;
+        }
+        crate::chain::render_tree(f, report.source().into_iter().chain(report.related()))
+    }
+
+    /// Context fields, revealing [`Secret`][crate::Secret]s when `internal`.
+    fn fields<E: Report + ?Sized>(&self, report: &E, internal: bool) -> Vec<Field> {
+        let context = match report.context() {
+            Some(context) => context,
+            None => return Vec::new(),
+        };
+
+        let mut fields = Vec::new();
+        report.visit_context(&mut |label, value| {
+            let value = if self.redaction.is_redacted(label) {
+                REDACTED.to_owned()
+            } else if internal {
+                self.truncate(Reveal(value).to_string())
+            } else {
+                self.truncate(value.to_string())
+            };
+            fields.push(Field {
+                label: Some(label.to_owned()),
+                value,
+            });
+        });
+        if fields.is_empty() {
+            let context = if internal {
+                Reveal(context).to_string()
+            } else {
+                context.to_string()
+            };
+            fields = context
+                .trim_end()
+                .lines()
+                .map(|l| Field {
+                    label: None,
+                    value: l.to_owned(),
+                })
+                .collect();
+        }
+        fields
+    }
+
+    fn truncate(&self, mut value: String) -> String {
+        if let Some(max) = self.max_value_len {
+            if let Some((end, _)) = value.char_indices().nth(max) {
+                let end = value[..end]
+                    .char_indices()
+                    .last()
+                    .map(|(i, _)| i)
+                    .unwrap_or(0);
+                value.truncate(end);
+                value.push('…');
+            }
+        }
+        value
+    }
+
+    /// Wrap `line` to the width, less the columns already used on the `first` and the `rest` of
+    /// the lines.
+    ///
+    /// Whitespace is kept, except where the line is broken.
+    fn wrap(&self, line: &str, first: usize, rest: usize) -> Vec<String> {
+        let width = match self.width {
+            Some(width) => width,
+            None => return vec![line.to_owned()],
+        };
+
+        let mut lines = Vec::new();
+        let mut available = width.saturating_sub(first).max(1);
+        let mut current = String::new();
+        let mut used = 0;
+        let mut remaining = line;
+        while !remaining.is_empty() {
+            let start = remaining
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(remaining.len());
+            let end = remaining[start..]
+                .find(char::is_whitespace)
+                .map(|i| start + i)
+                .unwrap_or(remaining.len());
+            let (space, word) = (&remaining[..start], &remaining[start..end]);
+            remaining = &remaining[end..];
+
+            let space_len = space.chars().count();
+            let word_len = word.chars().count();
+            if used != 0 && !word.is_empty() && used + space_len + word_len > available {
+                lines.push(std::mem::take(&mut current));
+                available = width.saturating_sub(rest).max(1);
+                used = 0;
+            } else {
+                current.push_str(space);
+                used += space_len;
+            }
+            current.push_str(word);
+            used += word_len;
+        }
+        lines.push(current);
+        lines
+    }
+
+    fn is_styled(&self) -> bool {
+        #[cfg(feature = "color")]
+        {
+            self.color
+        }
+        #[cfg(not(feature = "color"))]
+        {
+            false
+        }
+    }
+
+    fn paint(&self, f: &mut fmt::Formatter<'_>, role: Role, text: &str) -> fmt::Result {
+        #[cfg(feature = "color")]
+        {
+            if self.color {
+                let style = crate::color::style(role);
+                return write!(f, "{}{}{}", style.render(), text, style.render_reset());
+            }
+        }
+        let _ = role;
+        f.write_str(text)
+    }
+}
+
+/// Which context values a [`TextRenderer`] hides, by key.
+///
+/// Keys are compared ignoring ASCII case.  Values of context without keys, see
+/// [`Context::visit`][crate::Context::visit], are not redacted.
+///
+/// # Example
+///
+/// ```rust
+/// use status::Redaction;
+///
+/// let redaction = Redaction::new()
+///     .with_key("Email")
+///     .with_pattern("*token*");
+/// assert!(redaction.is_redacted("email"));
+/// assert!(redaction.is_redacted("API Token"));
+/// assert!(!redaction.is_redacted("Path"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Redaction {
+    rules: Vec<Rule>,
+}
+
+#[derive(Clone, Debug)]
+enum Rule {
+    Key(String),
+    Pattern(String),
+}
+
+impl Redaction {
+    /// Redact nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Redact the value for `key`.
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.rules.push(Rule::Key(key.into()));
+        self
+    }
+
+    /// Redact the values for keys matching the glob `pattern`.
+    ///
+    /// `*` matches any number of characters and `?` matches one character.
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.rules.push(Rule::Pattern(pattern.into()));
+        self
+    }
+
+    /// Whether the value for `key` is hidden.
+    pub fn is_redacted(&self, key: &str) -> bool {
+        self.rules.iter().any(|rule| match rule {
+            Rule::Key(name) => name.eq_ignore_ascii_case(key),
+            Rule::Pattern(pattern) => {
+                let pattern: Vec<_> = pattern.chars().collect();
+                let key: Vec<_> = key.chars().collect();
+                glob(&pattern, &key)
+            }
+        })
+    }
+}
+
+fn glob(pattern: &[char], text: &[char]) -> bool {
+    match (pattern.split_first(), text.split_first()) {
+        (None, _) => text.is_empty(),
+        (Some(('*', rest)), _) => (0..=text.len()).any(|i| glob(rest, &text[i..])),
+        (Some(_), None) => false,
+        (Some(('?', rest)), Some((_, text))) => glob(rest, text),
+        (Some((p, rest)), Some((t, text))) => p.eq_ignore_ascii_case(t) && glob(rest, text),
+    }
+}
+
+impl Renderer for TextRenderer {
+    fn render(&self, f: &mut fmt::Formatter<'_>, report: &dyn Report) -> fmt::Result {
+        self.write(f, report)
+    }
+}
+
+const INDENT: &str = "  ";
+const CAUSED_BY: &str = "Caused by: ";
+
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Role {
+    Kind,
+    Key,
+    Heading,
+}
+
+struct Field {
+    label: Option<String>,
+    value: String,
+}
+
+fn headline<E: Report + ?Sized>(report: &E) -> String {
+    let headline = match report.kind() {
+        Some(kind) if !report.severity().is_error() => format!("{}: {}", report.severity(), kind),
+        Some(kind) => kind.to_string(),
+        None => report.to_string(),
+    };
+    headline.trim_end().to_owned()
+}
+
+/// Messages for the chain of sources, skipping any that repeat the previous message.
+fn causes(headline: &str, source: Option<&(dyn Error + 'static)>) -> Vec<String> {
+    let mut causes: Vec<String> = Vec::new();
+    for cause in crate::Chain::new(source) {
+        let cause = cause.to_string().trim_end().to_owned();
+        let previous = causes.last().map(|c| c.as_str()).unwrap_or(headline);
+        if cause != previous {
+            causes.push(cause);
+        }
+    }
+    causes
+}
+
+fn single_line(text: &str) -> String {
+    let lines: Vec<_> = text.lines().map(|l| l.trim()).collect();
+    lines.join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io;
+
+    fn status() -> Status {
+        <Status>::new("Failed to read file")
+            .with_internal(io::Error::new(io::ErrorKind::NotFound, "No such file"))
+            .with_related(io::Error::other("Retried"))
+            .context_with(|c| {
+                c.insert("Path", "/home/user/projects/status/Cargo.toml")
+                    .insert("Expected", "A manifest")
+            })
+    }
+
+    fn render(renderer: TextRenderer, report: &dyn Report) -> String {
+        renderer.display(report).to_string()
+    }
+
+    #[test]
+    fn plain() {
+        let status = <Status>::new("Failed to read file")
+            .with_source(io::Error::new(io::ErrorKind::NotFound, "No such file"))
+            .with_related(io::Error::other("Retried"))
+            .context_with(|c| c.insert("Path", "Cargo.toml"));
+        assert_eq!(
+            render(TextRenderer::new(Format::Plain), &status),
+            "Failed to read file
+
+Path: Cargo.toml
+
+Related:
+└─ Retried
+
+Caused by: No such file
+"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "color")]
+    fn plain_color() {
+        let status = <Status>::new("Failed to read file")
+            .with_source(io::Error::new(io::ErrorKind::NotFound, "No such\nfile"));
+        assert_eq!(
+            render(TextRenderer::new(Format::Plain).with_color(true), &status),
+            "\u{1b}[1m\u{1b}[31mFailed to read file\u{1b}[0m
+
+\u{1b}[1mCaused by:\u{1b}[0m
+  No such
+  file
+"
+        );
+    }
+
+    #[test]
+    fn verbose() {
+        let output = render(TextRenderer::new(Format::Verbose), &status());
+        assert!(output.contains("No such file"), "{}", output);
+        let output = render(TextRenderer::new(Format::Plain), &status());
+        assert!(!output.contains("No such file"), "{}", output);
+    }
+
+    #[test]
+    fn compact() {
+        let status = status();
+        assert_eq!(
+            render(TextRenderer::new(Format::Compact), &status),
+            "Failed to read file (Path: /home/user/projects/status/Cargo.toml, Expected: A manifest) (+1 related)"
+        );
+    }
+
+    #[test]
+    fn tree() {
+        let status = <Status>::new("Failed to read file")
+            .with_source(io::Error::new(io::ErrorKind::NotFound, "No such file"))
+            .with_related(io::Error::other("Retried"))
+            .context_with(|c| c.insert("Path", "Cargo.toml"));
+        assert_eq!(
+            render(TextRenderer::new(Format::Tree), &status),
+            "Failed to read file
+Path: Cargo.toml
+├─ No such file
+└─ Retried
+"
+        );
+    }
+
+    #[test]
+    fn wrap_and_truncate() {
+        let status = <Status>::new("Failed to read the configuration file")
+            .context_with(|c| c.insert("Path", "/home/user/projects/status/Cargo.toml"));
+        let renderer = TextRenderer::new(Format::Plain)
+            .with_width(Some(20))
+            .with_max_value_len(Some(10));
+        assert_eq!(
+            render(renderer, &status),
+            "Failed to read the
+configuration file
+
+Path: /home/use…
+"
+        );
+    }
+
+    #[test]
+    fn wrap_keeps_whitespace() {
+        let status = <Status>::new("Failed to read file")
+            .with_source(io::Error::new(
+                io::ErrorKind::NotFound,
+                "No such file\n    in  the  workspace",
+            ))
+            .context_with(|c| c.insert("Path", "/home/user/projects/status/Cargo.toml"));
+        let renderer = TextRenderer::new(Format::Plain).with_width(Some(24));
+        assert_eq!(
+            render(renderer, &status),
+            "Failed to read file
+
+Path: /home/user/projects/status/Cargo.toml
+
+Caused by: No such file
+    in  the  workspace
+"
+        );
+
+        let status = <Status>::new("Failed")
+            .context_with(|c| c.insert("Expected", "A  manifest or a workspace"));
+        let renderer = TextRenderer::new(Format::Plain).with_width(Some(24));
+        assert_eq!(
+            render(renderer, &status),
+            "Failed
+
+Expected: A  manifest or
+          a workspace
+"
+        );
+    }
+
+    #[test]
+    fn dedup_causes() {
+        let status = <Status>::new("Failed to read file").with_source(
+            Status::<crate::Unkind, crate::NoContext>::new("No such file")
+                .with_source(io::Error::new(io::ErrorKind::NotFound, "No such file")),
+        );
+        assert_eq!(
+            causes("Failed to read file", std::error::Error::source(&status)),
+            ["No such file"]
+        );
+    }
+
+    #[test]
+    fn redaction() {
+        let status = <Status>::new("Failed to log in").context_with(|c| {
+            c.insert("User", "epage")
+                .insert("Token", crate::Secret::new("hunter2"))
+                .insert("Password", "swordfish")
+        });
+        let redaction = Redaction::new().with_key("password");
+        assert_eq!(
+            render(
+                TextRenderer::new(Format::Plain).with_redaction(redaction.clone()),
+                &status
+            ),
+            "Failed to log in
+
+User: epage
+Token: [redacted]
+Password: [redacted]
+"
+        );
+        let output = render(
+            TextRenderer::new(Format::Verbose).with_redaction(redaction),
+            &status,
+        );
+        assert!(
+            output.starts_with(
+                "Failed to log in
+
+User: epage
+Token: hunter2
+Password: [redacted]
+"
+            ),
+            "{}",
+            output
+        );
+    }
+
+    #[test]
+    fn backtrace() {
+        let mut status = <Status>::new("Failed to read file");
+        status.inner.backtrace = Some(std::backtrace::Backtrace::force_capture());
+        let output = render(TextRenderer::new(Format::Plain), &status);
+        assert_eq!(output, "Failed to read file\n");
+        let output = render(TextRenderer::new(Format::Verbose), &status);
+        assert!(output.contains("Backtrace:"), "{}", output);
+
+        let status = status.into_internal();
+        let output = render(TextRenderer::new(Format::Plain), &status);
+        assert!(output.contains("Backtrace:"), "{}", output);
+    }
+
+    #[test]
+    fn redaction_pattern() {
+        let redaction = Redaction::new().with_pattern("*_key?");
+        assert!(redaction.is_redacted("API_KEYS"));
+        assert!(redaction.is_redacted("_key1"));
+        assert!(!redaction.is_redacted("api_key"));
+        assert!(!redaction.is_redacted("key_api"));
+    }
+
+    #[test]
+    fn format_from_str() {
+        assert_eq!("Tree".parse::<Format>().unwrap(), Format::Tree);
+        assert!("fancy".parse::<Format>().is_err());
+    }
+}
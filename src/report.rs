@@ -0,0 +1,68 @@
+use core::error;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::backtrace::Backtrace;
+
+use crate::Related;
+use crate::Severity;
+
+/// Diagnostic details for reporting a status to the user, beyond what `Error` provides.
+///
+/// The structured accessors let a renderer style each part of the status separately.  When they
+/// aren't implemented, renderers fall back to `Display`.
+///
+/// See [`TerminatingStatus`][crate::TerminatingStatus].
+pub trait Report: error::Error {
+    /// The message for the kind of status, without its context.
+    fn kind(&self) -> Option<&dyn fmt::Display> {
+        None
+    }
+
+    /// How serious the status is.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// The context of the status, if any is populated.
+    fn context(&self) -> Option<&dyn fmt::Display> {
+        None
+    }
+
+    /// Visit each populated context field as a label and value.
+    ///
+    /// See [`Context::visit`][crate::Context::visit].
+    fn visit_context(&self, visitor: &mut dyn FnMut(&str, &dyn fmt::Display)) {
+        let _ = visitor;
+    }
+
+    /// Errors related to this one.
+    fn related(&self) -> Related<'_> {
+        Related::new(&[], false)
+    }
+
+    /// The source, private or public.
+    ///
+    /// See [`InternalStatus`][crate::InternalStatus].
+    fn internal_source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.source()
+    }
+
+    /// Errors related to this one, private or public.
+    fn internal_related(&self) -> Related<'_> {
+        self.related()
+    }
+
+    /// The backtrace captured when the error was created, if it may be shown to users.
+    #[cfg(feature = "std")]
+    fn backtrace(&self) -> Option<&Backtrace> {
+        None
+    }
+
+    /// The backtrace captured when the error was created, private or public.
+    ///
+    /// See [`InternalStatus`][crate::InternalStatus].
+    #[cfg(feature = "std")]
+    fn internal_backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace()
+    }
+}
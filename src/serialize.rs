@@ -0,0 +1,402 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::any;
+use core::convert::TryFrom;
+use core::error;
+use core::fmt;
+
+use serde::de;
+use serde::ser;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::AdhocContext;
+use crate::Code;
+use crate::Context;
+use crate::InternalStatus;
+use crate::Kind;
+use crate::Severity;
+use crate::Source;
+use crate::Status;
+use crate::StatusDetails;
+use crate::Unkind;
+
+/// A source error reconstructed from a serialized [`Status`].
+///
+/// Only the rendered message of each source survives serialization, so this is what you will find
+/// when walking [`Status::sources`] on a deserialized [`Status`].
+#[derive(Clone, Debug)]
+pub struct SerializedSource {
+    message: String,
+    source: Option<Box<SerializedSource>>,
+}
+
+impl SerializedSource {
+    fn from_chain(chain: Vec<String>) -> Option<Self> {
+        chain.into_iter().rev().fold(None, |source, message| {
+            Some(Self {
+                message,
+                source: source.map(Box::new),
+            })
+        })
+    }
+
+    /// The rendered message of the original source.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for SerializedSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for SerializedSource {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|s| s.as_ref() as &(dyn error::Error + 'static))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Visibility {
+    Public,
+    Private,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SourceRepr {
+    visibility: Visibility,
+    chain: Vec<String>,
+}
+
+impl SourceRepr {
+    fn new(source: &Source, internal: bool) -> Option<Self> {
+        let (visibility, error) = match source {
+            Source::Public(e, _) => (Visibility::Public, e.as_ref()),
+            Source::Private(e, _) if internal => (Visibility::Private, e.as_ref()),
+            _ => return None,
+        };
+        let error: &crate::StdError = error;
+        let chain = crate::Chain::new(Some(error))
+            .map(|e| e.to_string())
+            .collect();
+        Some(Self { visibility, chain })
+    }
+
+    fn into_source(self) -> Source {
+        match SerializedSource::from_chain(self.chain) {
+            Some(error) => match self.visibility {
+                Visibility::Public => {
+                    Source::Public(Box::new(error), any::type_name::<SerializedSource>())
+                }
+                Visibility::Private => {
+                    Source::Private(Box::new(error), any::type_name::<SerializedSource>())
+                }
+            },
+            None => Source::Empty,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct StatusRef<'s, K, C> {
+    kind: &'s K,
+    #[serde(skip_serializing_if = "Severity::is_error")]
+    severity: Severity,
+    context: &'s C,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<SourceRepr>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related: Vec<SourceRepr>,
+}
+
+impl<'s, K: Kind, C: Context> StatusRef<'s, K, C> {
+    fn new(status: &'s Status<K, C>, internal: bool) -> Self {
+        Self {
+            kind: &status.inner.kind,
+            severity: status.inner.severity,
+            context: &status.inner.data,
+            source: SourceRepr::new(&status.inner.source, internal),
+            related: status
+                .inner
+                .related
+                .iter()
+                .filter_map(|s| SourceRepr::new(s, internal))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct StatusRepr<K, C: Default> {
+    kind: K,
+    #[serde(default)]
+    severity: Severity,
+    #[serde(default)]
+    context: C,
+    #[serde(default)]
+    source: Option<SourceRepr>,
+    #[serde(default)]
+    related: Vec<SourceRepr>,
+}
+
+/// Serializes the [`Kind`], [`Context`], and a snapshot of the public sources and related errors.
+///
+/// To include private sources, serialize the [`InternalStatus`] instead.
+impl<K, C> Serialize for Status<K, C>
+where
+    K: Kind + Serialize,
+    C: Context + Serialize,
+{
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StatusRef::new(self, false).serialize(serializer)
+    }
+}
+
+/// Deserialized sources are reconstructed as a chain of [`SerializedSource`], retaining their
+/// visibility.
+impl<'de, K, C> Deserialize<'de> for Status<K, C>
+where
+    K: Kind + Deserialize<'de>,
+    C: Context + Deserialize<'de>,
+{
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = StatusRepr::<K, C>::deserialize(deserializer)?;
+        crate::chain::register_status::<K, C>();
+        Ok(Self {
+            inner: Box::new(StatusDetails {
+                kind: repr.kind,
+                severity: repr.severity,
+                source: repr
+                    .source
+                    .map(SourceRepr::into_source)
+                    .unwrap_or(Source::Empty),
+                related: repr
+                    .related
+                    .into_iter()
+                    .map(SourceRepr::into_source)
+                    .filter(|s| s.any().is_some())
+                    .collect(),
+                data: repr.context,
+                #[cfg(feature = "std")]
+                backtrace: None,
+                #[cfg(feature = "tracing")]
+                span_trace: None,
+            }),
+        })
+    }
+}
+
+/// Serializes the [`Kind`], [`Context`], and a snapshot of the sources and related errors, private
+/// or public.
+impl<K, C> Serialize for InternalStatus<K, C>
+where
+    K: Kind + Serialize,
+    C: Context + Serialize,
+{
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StatusRef::new(&self.0, true).serialize(serializer)
+    }
+}
+
+impl<'de, K, C> Deserialize<'de> for InternalStatus<K, C>
+where
+    K: Kind + Deserialize<'de>,
+    C: Context + Deserialize<'de>,
+{
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Status::deserialize(deserializer).map(InternalStatus::new)
+    }
+}
+
+impl Serialize for Unkind {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.inner)
+    }
+}
+
+impl<'de> Deserialize<'de> for Unkind {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Serializes as a map of keys to the `Display` of their value.
+impl Serialize for AdhocContext {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.data.len()))?;
+        for (k, v) in &self.data {
+            map.serialize_entry(k, &v.to_string())?;
+        }
+        map.end()
+    }
+}
+
+/// Values are deserialized as `String`s.
+impl<'de> Deserialize<'de> for AdhocContext {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AdhocVisitor;
+
+        impl<'de> de::Visitor<'de> for AdhocVisitor {
+            type Value = AdhocContext;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a map of context")
+            }
+
+            fn visit_map<M: de::MapAccess<'de>>(
+                self,
+                mut access: M,
+            ) -> Result<Self::Value, M::Error> {
+                let mut context = AdhocContext::new();
+                while let Some((k, v)) = access.next_entry::<String, String>()? {
+                    context = context.insert(k, v);
+                }
+                Ok(context)
+            }
+        }
+
+        deserializer.deserialize_map(AdhocVisitor)
+    }
+}
+
+/// Serializes as an integer or string.
+impl Serialize for Code {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Int(code) => serializer.serialize_i64(*code),
+            Self::Str(code) => serializer.serialize_str(code),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Code {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CodeVisitor;
+
+        impl<'de> de::Visitor<'de> for CodeVisitor {
+            type Value = Code;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "an integer or string code")
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(Code::Int(v))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                i64::try_from(v)
+                    .map(Code::Int)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(Code::from(String::from(v)))
+            }
+        }
+
+        deserializer.deserialize_any(CodeVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::NoContext;
+
+    use std::io;
+
+    #[derive(Copy, Clone, Debug, PartialEq, derive_more::Display, Serialize, Deserialize)]
+    enum ErrorKind {
+        #[display(fmt = "Failed to read file")]
+        Read,
+    }
+
+    #[test]
+    fn public_sources() {
+        let status = <Status<ErrorKind>>::new(ErrorKind::Read)
+            .with_source(io::Error::new(io::ErrorKind::NotFound, "missing"))
+            .context_with(|c| c.insert("Expected value", 5));
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"Read","context":{"Expected value":"5"},"source":{"visibility":"public","chain":["missing"]}}"#
+        );
+
+        let status: Status<ErrorKind> = serde_json::from_str(&json).unwrap();
+        assert_eq!(status.kind(), ErrorKind::Read);
+        assert_eq!(status.context().to_string(), "Expected value: 5\n");
+        let sources: Vec<_> = status.sources().map(|e| e.to_string()).collect();
+        assert_eq!(sources, ["missing"]);
+    }
+
+    #[test]
+    fn private_sources() {
+        let status = Status::<ErrorKind, NoContext>::new(ErrorKind::Read)
+            .with_internal(io::Error::new(io::ErrorKind::NotFound, "missing"));
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(json, r#"{"kind":"Read","context":null}"#);
+
+        let json = serde_json::to_string(&status.into_internal()).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"Read","context":null,"source":{"visibility":"private","chain":["missing"]}}"#
+        );
+
+        let status: Status<ErrorKind, NoContext> = serde_json::from_str(&json).unwrap();
+        assert_eq!(status.sources().count(), 0);
+        assert_eq!(status.into_internal().sources().count(), 1);
+    }
+
+    #[test]
+    fn related() {
+        let status = Status::<ErrorKind, NoContext>::new(ErrorKind::Read)
+            .with_related(io::Error::new(io::ErrorKind::NotFound, "missing"))
+            .with_related_internal(io::Error::new(io::ErrorKind::NotFound, "hidden"));
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"Read","context":null,"related":[{"visibility":"public","chain":["missing"]}]}"#
+        );
+
+        let json = serde_json::to_string(&status.into_internal()).unwrap();
+        let status: Status<ErrorKind, NoContext> = serde_json::from_str(&json).unwrap();
+        assert_eq!(status.related().count(), 1);
+        assert_eq!(status.into_internal().related().count(), 2);
+    }
+
+    #[test]
+    fn code() {
+        let json = serde_json::to_string(&[Code::Int(1042), Code::from("io.read.E1042")]).unwrap();
+        assert_eq!(json, r#"[1042,"io.read.E1042"]"#);
+
+        let codes: Vec<Code> = serde_json::from_str(&json).unwrap();
+        assert_eq!(codes, [Code::Int(1042), Code::from("io.read.E1042")]);
+    }
+
+    #[test]
+    fn unkind() {
+        let status: Status = Status::new("Died");
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(json, r#"{"kind":"Died","context":{}}"#);
+
+        let status: Status = serde_json::from_str(&json).unwrap();
+        assert_eq!(status.kind().to_string(), "Died\n");
+
+        let message = String::from("Failed to read file");
+        let json = serde_json::to_string(&<Status>::new(message)).unwrap();
+        let status: Status = serde_json::from_str(&json).unwrap();
+        assert_eq!(status.kind().to_string(), "Failed to read file\n");
+    }
+}
@@ -1,11 +1,24 @@
-use std::error;
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any;
+use core::error;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::backtrace::Backtrace;
+#[cfg(feature = "std")]
+use std::backtrace::BacktraceStatus;
 
 use crate::AdhocContext;
 use crate::Chain;
+use crate::Code;
 use crate::Context;
 use crate::InternalStatus;
+use crate::Json;
 use crate::Kind;
+use crate::KindCode;
+use crate::Related;
+use crate::Report;
+use crate::Severity;
 use crate::StdError;
 use crate::StrictError;
 use crate::Unkind;
@@ -46,13 +59,23 @@ pub struct Status<K: Kind = Unkind, C: Context = AdhocContext> {
 #[derive(Debug)]
 pub(crate) struct StatusDetails<K: Kind, C: Context> {
     pub(crate) kind: K,
+    pub(crate) severity: Severity,
     pub(crate) source: Source,
+    pub(crate) related: Vec<Source>,
     pub(crate) data: C,
+    #[cfg(feature = "std")]
+    pub(crate) backtrace: Option<Backtrace>,
+    #[cfg(feature = "tracing")]
+    pub(crate) span_trace: Option<tracing_error::SpanTrace>,
 }
 
 impl<K: Kind, C: Context> Status<K, C> {
     /// Create a container for the specified status [`Kind`].
     ///
+    /// When `RUST_LIB_BACKTRACE` or `RUST_BACKTRACE` enable it, a backtrace is captured for
+    /// [`InternalStatus::backtrace`].  With the `tracing` feature, the current span trace is
+    /// captured as well.
+    ///
     /// # Example
     ///
     /// ```
@@ -67,47 +90,154 @@ impl<K: Kind, C: Context> Status<K, C> {
         Self {
             inner: Box::new(StatusDetails {
                 kind: kind.into(),
+                severity: Severity::Error,
                 source: Source::Empty,
+                related: Vec::new(),
                 data: Default::default(),
+                #[cfg(feature = "std")]
+                backtrace: capture_backtrace(),
+                #[cfg(feature = "tracing")]
+                span_trace: crate::trace::capture_span_trace(),
             }),
         }
     }
 
     /// Add a public error.
+    ///
+    /// This replaces any previous source.  To report several errors, see
+    /// [`Status::with_related`].
     #[cfg(feature = "send_sync")]
     pub fn with_source<E>(mut self, error: E) -> Self
     where
         E: error::Error + Send + Sync + 'static,
     {
-        self.inner.source = Source::Public(Box::new(error));
+        self.inner.source = Source::Public(Box::new(error), any::type_name::<E>());
         self
     }
     /// Add a public error.
+    ///
+    /// This replaces any previous source.  To report several errors, see
+    /// [`Status::with_related`].
     #[cfg(not(feature = "send_sync"))]
     pub fn with_source<E>(mut self, error: E) -> Self
     where
         E: error::Error + 'static,
     {
-        self.inner.source = Source::Public(Box::new(error));
+        self.inner.source = Source::Public(Box::new(error), any::type_name::<E>());
         self
     }
 
     #[cfg(feature = "send_sync")]
     /// Add an internal error.
+    ///
+    /// This replaces any previous source.  To report several errors, see
+    /// [`Status::with_related_internal`].
     pub fn with_internal<E>(mut self, error: E) -> Self
     where
         E: error::Error + Send + Sync + 'static,
     {
-        self.inner.source = Source::Private(Box::new(error));
+        crate::chain::register_status::<K, C>();
+        self.inner.source = Source::Private(Box::new(error), any::type_name::<E>());
         self
     }
     #[cfg(not(feature = "send_sync"))]
     /// Add an internal error.
+    ///
+    /// This replaces any previous source.  To report several errors, see
+    /// [`Status::with_related_internal`].
     pub fn with_internal<E>(mut self, error: E) -> Self
     where
         E: error::Error + 'static,
     {
-        self.inner.source = Source::Private(Box::new(error));
+        crate::chain::register_status::<K, C>();
+        self.inner.source = Source::Private(Box::new(error), any::type_name::<E>());
+        self
+    }
+
+    /// Add a public error that is related to, rather than the cause of, this status.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::io;
+    /// type Status = status::Status;
+    ///
+    /// let status = Status::new("Failed to sync shards")
+    ///     .with_related(io::Error::new(io::ErrorKind::TimedOut, "Shard 1 timed out"))
+    ///     .with_related(io::Error::new(io::ErrorKind::TimedOut, "Shard 3 timed out"));
+    /// assert_eq!(status.related().count(), 2);
+    /// ```
+    #[cfg(feature = "send_sync")]
+    pub fn with_related<E>(mut self, error: E) -> Self
+    where
+        E: error::Error + Send + Sync + 'static,
+    {
+        self.inner
+            .related
+            .push(Source::Public(Box::new(error), any::type_name::<E>()));
+        self
+    }
+    /// Add a public error that is related to, rather than the cause of, this status.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::io;
+    /// type Status = status::Status;
+    ///
+    /// let status = Status::new("Failed to sync shards")
+    ///     .with_related(io::Error::new(io::ErrorKind::TimedOut, "Shard 1 timed out"))
+    ///     .with_related(io::Error::new(io::ErrorKind::TimedOut, "Shard 3 timed out"));
+    /// assert_eq!(status.related().count(), 2);
+    /// ```
+    #[cfg(not(feature = "send_sync"))]
+    pub fn with_related<E>(mut self, error: E) -> Self
+    where
+        E: error::Error + 'static,
+    {
+        self.inner
+            .related
+            .push(Source::Public(Box::new(error), any::type_name::<E>()));
+        self
+    }
+
+    /// Add an internal error that is related to, rather than the cause of, this status.
+    #[cfg(feature = "send_sync")]
+    pub fn with_related_internal<E>(mut self, error: E) -> Self
+    where
+        E: error::Error + Send + Sync + 'static,
+    {
+        self.inner
+            .related
+            .push(Source::Private(Box::new(error), any::type_name::<E>()));
+        self
+    }
+    /// Add an internal error that is related to, rather than the cause of, this status.
+    #[cfg(not(feature = "send_sync"))]
+    pub fn with_related_internal<E>(mut self, error: E) -> Self
+    where
+        E: error::Error + 'static,
+    {
+        self.inner
+            .related
+            .push(Source::Private(Box::new(error), any::type_name::<E>()));
+        self
+    }
+
+    /// Change how serious this status is, see [`Diagnostics`][crate::Diagnostics].
+    ///
+    /// Anything other than [`Severity::Error`] is shown before the [`Kind`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use status::Severity;
+    ///
+    /// let status = <status::Status>::new("Unused key").with_severity(Severity::Warning);
+    /// assert!(status.to_string().starts_with("warning: Unused key"));
+    /// ```
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.inner.severity = severity;
         self
     }
 
@@ -117,12 +247,87 @@ impl<K: Kind, C: Context> Status<K, C> {
         F: Fn(C) -> C,
     {
         let mut data: C = Default::default();
-        std::mem::swap(&mut data, &mut self.inner.data);
+        core::mem::swap(&mut data, &mut self.inner.data);
         let mut data = context(data);
-        std::mem::swap(&mut data, &mut self.inner.data);
+        core::mem::swap(&mut data, &mut self.inner.data);
         self
     }
 
+    /// Convert to a different [`Kind`] and [`Context`], keeping the sources, related errors, and
+    /// backtrace.
+    ///
+    /// This is the stand-in for `impl From<Status<K, C>>` when passing a status up a layer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[derive(Copy, Clone, Debug, derive_more::Display)]
+    /// enum StorageKind {
+    ///   #[display(fmt = "Disk is full")]
+    ///   Full,
+    /// }
+    ///
+    /// #[derive(Copy, Clone, Debug, derive_more::Display)]
+    /// enum AppKind {
+    ///   #[display(fmt = "Failed to save")]
+    ///   Save(StorageKind),
+    /// }
+    ///
+    /// let status = status::Status::<StorageKind>::new(StorageKind::Full)
+    ///     .context_with(|c| c.insert("Path", "notes.txt"));
+    /// let status = status.map(|kind, context| (AppKind::Save(kind), context));
+    /// assert!(matches!(status.kind(), AppKind::Save(StorageKind::Full)));
+    /// ```
+    pub fn map<K2, C2, F>(self, f: F) -> Status<K2, C2>
+    where
+        K2: Kind,
+        C2: Context,
+        F: FnOnce(K, C) -> (K2, C2),
+    {
+        let inner = *self.inner;
+        let (kind, data) = f(inner.kind, inner.data);
+        Status {
+            inner: Box::new(StatusDetails {
+                kind,
+                severity: inner.severity,
+                source: inner.source,
+                related: inner.related,
+                data,
+                #[cfg(feature = "std")]
+                backtrace: inner.backtrace,
+                #[cfg(feature = "tracing")]
+                span_trace: inner.span_trace,
+            }),
+        }
+    }
+
+    /// Convert to a different [`Kind`], keeping everything else.
+    ///
+    /// See [`Status::map`].
+    pub fn map_kind<K2, F>(self, f: F) -> Status<K2, C>
+    where
+        K2: Kind,
+        F: FnOnce(K) -> K2,
+    {
+        self.map(|kind, context| (f(kind), context))
+    }
+
+    /// Convert to a different [`Context`], keeping everything else.
+    ///
+    /// See [`Status::map`].
+    pub fn map_context<C2, F>(self, f: F) -> Status<K, C2>
+    where
+        C2: Context,
+        F: FnOnce(C) -> C2,
+    {
+        self.map(|kind, context| (kind, f(context)))
+    }
+
+    /// How serious this status is.
+    pub fn severity(&self) -> Severity {
+        self.inner.severity
+    }
+
     /// Access the [`Context`] for programmatic usage.
     pub fn context(&self) -> &C {
         &self.inner.data
@@ -160,7 +365,7 @@ impl<K: Kind, C: Context> Status<K, C> {
     /// }
     /// ```
     pub fn kind(&self) -> K {
-        self.inner.kind
+        self.inner.kind.clone()
     }
 
     /// An iterator for the chain of sources.
@@ -182,7 +387,7 @@ impl<K: Kind, C: Context> Status<K, C> {
     ///     None
     /// }
     /// ```
-    pub fn sources(&self) -> Chain {
+    pub fn sources(&self) -> Chain<'_> {
         Chain::new(error::Error::source(self))
     }
 
@@ -210,6 +415,41 @@ impl<K: Kind, C: Context> Status<K, C> {
         self.sources().last()
     }
 
+    /// The first public source of type `T`, searching the whole chain.
+    ///
+    /// A nested [`Status`] only exposes its public sources, so errors behind a private source
+    /// can only be found with [`InternalStatus::find_source`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::io;
+    /// #[derive(Copy, Clone, Debug, derive_more::Display)]
+    /// enum IoKind {
+    ///   #[display(fmt = "Failed to read file")]
+    ///   Read,
+    /// }
+    ///
+    /// fn should_retry(status: &status::Status) -> bool {
+    ///     let timed_out = status
+    ///         .find_source::<io::Error>()
+    ///         .map(|e| e.kind() == io::ErrorKind::TimedOut)
+    ///         .unwrap_or(false);
+    ///     let read = status.find_source::<status::Status<IoKind>>().is_some();
+    ///     timed_out || read
+    /// }
+    /// ```
+    pub fn find_source<T: error::Error + 'static>(&self) -> Option<&T> {
+        self.sources().downcast::<T>().next()
+    }
+
+    /// An iterator over the public related errors.
+    ///
+    /// When debugging, to include internal related errors, run [`Status::into_internal`].
+    pub fn related(&self) -> Related<'_> {
+        Related::new(&self.inner.related, false)
+    }
+
     /// View of [`Status`], exposing implementation details.
     ///
     /// `Error::source` and [`InternalStatus::sources`] are for debug / display purposes only and
@@ -231,24 +471,61 @@ impl<K: Kind, C: Context> Status<K, C> {
         InternalStatus::new(self)
     }
 
+    /// Render as a JSON object, for structured logs.
+    ///
+    /// Only the public sources are included.  See [`Json`].
+    pub fn json(&self) -> Json<'_, K, C> {
+        Json::new(self, false)
+    }
+
     /// Convenience for returning an error.
     pub fn into_err<T>(self) -> Result<T, Self> {
         Err(self)
     }
 }
 
-impl<K: Kind, C: Context> fmt::Display for Status<K, C> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<K: KindCode, C: Context> Status<K, C> {
+    /// Stable identifier for which error occurred.
+    ///
+    /// See [`KindCode`].
+    pub fn code(&self) -> Code {
+        self.inner.kind.code()
+    }
+}
+
+impl<K: Kind, C: Context> Status<K, C> {
+    pub(crate) fn render(&self, f: &mut fmt::Formatter<'_>, internal: bool) -> fmt::Result {
+        if !self.inner.severity.is_error() {
+            write!(f, "{}: ", self.inner.severity)?;
+        }
         writeln!(f, "{}", self.inner.kind)?;
         if !self.inner.data.is_empty() {
             writeln!(f)?;
-            writeln!(f, "{}", self.inner.data)?;
+            if internal {
+                writeln!(f, "{}", crate::context::Reveal(&self.inner.data))?;
+            } else {
+                writeln!(f, "{}", self.inner.data)?;
+            }
+        }
+        let mut related = Related::new(&self.inner.related, internal).peekable();
+        if related.peek().is_some() {
+            if self.inner.data.is_empty() {
+                writeln!(f)?;
+            }
+            writeln!(f, "Related:")?;
+            crate::chain::render_tree(f, related)?;
         }
         Ok(())
     }
 }
 
-impl<K: Kind, C: Context> std::ops::Deref for Status<K, C> {
+impl<K: Kind, C: Context> fmt::Display for Status<K, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, false)
+    }
+}
+
+impl<K: Kind, C: Context> core::ops::Deref for Status<K, C> {
     type Target = C;
 
     fn deref(&self) -> &Self::Target {
@@ -256,7 +533,7 @@ impl<K: Kind, C: Context> std::ops::Deref for Status<K, C> {
     }
 }
 
-impl<K: Kind, C: Context> std::ops::DerefMut for Status<K, C> {
+impl<K: Kind, C: Context> core::ops::DerefMut for Status<K, C> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.inner.data
     }
@@ -272,31 +549,94 @@ impl<K: Kind, C: Context> error::Error for Status<K, C> {
     }
 }
 
+impl<K: Kind, C: Context> Report for Status<K, C> {
+    fn kind(&self) -> Option<&dyn fmt::Display> {
+        Some(&self.inner.kind)
+    }
+
+    fn severity(&self) -> Severity {
+        self.inner.severity
+    }
+
+    fn context(&self) -> Option<&dyn fmt::Display> {
+        Some(&self.inner.data)
+            .filter(|c| !c.is_empty())
+            .map(|c| c as _)
+    }
+
+    fn visit_context(&self, visitor: &mut dyn FnMut(&str, &dyn fmt::Display)) {
+        self.inner.data.visit(visitor)
+    }
+
+    fn related(&self) -> Related<'_> {
+        Status::related(self)
+    }
+
+    fn internal_source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.inner.source.any()
+    }
+
+    fn internal_related(&self) -> Related<'_> {
+        Related::new(&self.inner.related, true)
+    }
+
+    #[cfg(feature = "std")]
+    fn internal_backtrace(&self) -> Option<&Backtrace> {
+        self.inner.backtrace.as_ref()
+    }
+}
+
+#[cfg(feature = "std")]
+fn capture_backtrace() -> Option<Backtrace> {
+    let backtrace = Backtrace::capture();
+    match backtrace.status() {
+        BacktraceStatus::Captured => Some(backtrace),
+        _ => None,
+    }
+}
+
 // impl From<Kind> is waiting on specialization
 
-// impl From<Error> is waiting on specialization
+// impl From<Error> is waiting on specialization, see `Classify` instead
 
 #[derive(Debug)]
 pub(crate) enum Source {
-    Public(Box<StrictError>),
-    Private(Box<StrictError>),
+    /// The error and the name of its type.
+    Public(Box<StrictError>, &'static str),
+    /// The error and the name of its type.
+    Private(Box<StrictError>, &'static str),
     Empty,
 }
 
 impl Source {
     pub(crate) fn public(&self) -> Option<&StdError> {
         match self {
-            Self::Public(e) => Some(e.as_ref()),
+            Self::Public(e, _) => Some(e.as_ref()),
             _ => None,
         }
     }
 
     pub(crate) fn any(&self) -> Option<&StdError> {
         match self {
-            Self::Public(e) | Self::Private(e) => Some(e.as_ref()),
+            Self::Public(e, _) | Self::Private(e, _) => Some(e.as_ref()),
             _ => None,
         }
     }
+
+    pub(crate) fn type_name(&self) -> Option<&'static str> {
+        match self {
+            Self::Public(_, name) | Self::Private(_, name) => Some(name),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get(&self, internal: bool) -> Option<&StdError> {
+        if internal {
+            self.any()
+        } else {
+            self.public()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -318,4 +658,68 @@ mod test {
         #[cfg(feature = "send_sync")]
         assert_impl_all!(Status: Send, Sync);
     }
+
+    #[test]
+    fn related() {
+        #[derive(Debug, derive_more::Display)]
+        #[display(fmt = "Connection refused")]
+        struct Refused;
+        impl error::Error for Refused {}
+
+        #[derive(Debug, derive_more::Display)]
+        #[display(fmt = "Shard {}\nfailed", _0)]
+        struct Shard(usize, Option<Refused>);
+        impl error::Error for Shard {
+            fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+                self.1.as_ref().map(|e| e as &(dyn error::Error + 'static))
+            }
+        }
+
+        let status = <Status>::new("Failed to sync shards")
+            .with_related(Shard(1, Some(Refused)))
+            .with_related_internal(Shard(2, None))
+            .with_related(Shard(3, None));
+        assert_eq!(status.related().count(), 2);
+        assert_eq!(
+            status.to_string(),
+            "Failed to sync shards\n\n\n\
+             Related:\n\
+             ├─ Shard 1\n\
+             │  failed\n\
+             │  └─ Connection refused\n\
+             └─ Shard 3\n   \
+             failed\n"
+        );
+
+        let status = status.into_internal();
+        assert_eq!(status.related().count(), 3);
+        assert!(status.to_string().contains("├─ Shard 2\n"));
+    }
+    #[test]
+    fn map() {
+        #[derive(Copy, Clone, Debug, PartialEq, derive_more::Display)]
+        enum ErrorKind {
+            #[display(fmt = "Failed to read file")]
+            Read,
+        }
+
+        let status = <Status>::new("Disk is full")
+            .with_source(Status::<Unkind, crate::NoContext>::new("Bad sector"))
+            .with_related(Status::<Unkind, crate::NoContext>::new("Retried"))
+            .context_with(|c| c.insert("Path", "Cargo.toml"));
+        let status = status
+            .map_kind(|_| ErrorKind::Read)
+            .map_context(|c| c.insert("Attempt", 2));
+        assert_eq!(status.kind(), ErrorKind::Read);
+        assert_eq!(status.get::<&str>("Path"), Some(&"Cargo.toml"));
+        assert_eq!(status.get::<i32>("Attempt"), Some(&2));
+        assert_eq!(status.sources().count(), 1);
+        assert_eq!(status.related().count(), 1);
+
+        let status = status.map(|kind, _| (kind, crate::NoContext));
+        assert_eq!(
+            status.to_string(),
+            "Failed to read file\n\nRelated:\n└─ Retried\n"
+        );
+    }
 }
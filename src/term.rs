@@ -1,8 +1,20 @@
-use std::error;
-use std::fmt;
+use core::error;
+use core::fmt;
+
+#[cfg(feature = "std")]
+use crate::Report;
 
 /// For use with `main`
 ///
+/// The status is rendered with the global [`renderer`][crate::renderer()], see
+/// [`set_renderer`][crate::set_renderer].
+///
+/// Returning `Result<(), TerminatingStatus>` always exits with `1` and only renders what `Error`
+/// provides.  To exit with the [`ExitCode`][crate::ExitCode] of the [`Kind`][crate::Kind] and
+/// render the full [`Report`][crate::Report], like the context, return a [`TerminatingResult`] or the
+/// `TerminatingStatus` itself.  The backtrace of a [`Status`][crate::Status] is only shown with
+/// [`Format::Verbose`][crate::Format::Verbose] or through an [`InternalStatus`][crate::InternalStatus].
+///
 /// # Example
 ///
 /// ```rust,should_panic
@@ -22,6 +34,12 @@ impl<E: error::Error> From<E> for TerminatingStatus<E> {
 }
 
 impl<E: error::Error> fmt::Debug for TerminatingStatus<E> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", crate::render(&Plain(&self.error)))
+    }
+
+    #[cfg(not(feature = "std"))]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "{}", self.error)?;
         for source in crate::Chain::new(self.error.source()) {
@@ -31,3 +49,148 @@ impl<E: error::Error> fmt::Debug for TerminatingStatus<E> {
         Ok(())
     }
 }
+
+#[cfg(feature = "std")]
+impl<K: crate::ExitCode, C: crate::Context> std::process::Termination
+    for TerminatingStatus<crate::Status<K, C>>
+{
+    fn report(self) -> std::process::ExitCode {
+        let code = self.error.kind().exit_code();
+        eprintln!("Error: {}", crate::render(&self.error));
+        code.into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: crate::ExitCode, C: crate::Context> std::process::Termination
+    for TerminatingStatus<crate::InternalStatus<K, C>>
+{
+    fn report(self) -> std::process::ExitCode {
+        let code = self.error.0.kind().exit_code();
+        eprintln!("Error: {}", crate::render(&self.error));
+        code.into()
+    }
+}
+
+/// An `Error` without any [`Report`] details, for rendering.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct Plain<'a, E>(&'a E);
+
+#[cfg(feature = "std")]
+impl<E: error::Error> fmt::Display for Plain<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: error::Error> error::Error for Plain<'_, E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.0.source()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: error::Error> Report for Plain<'_, E> {}
+
+/// For use with `main`, exiting with the [`ExitCode`][crate::ExitCode] of the
+/// [`Kind`][crate::Kind].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// type Status = status::Status;
+///
+/// fn run() -> Result<(), Status> {
+///     Err(Status::new("Died"))
+/// }
+///
+/// # #[cfg(feature = "std")]
+/// fn main() -> status::TerminatingResult {
+///     run().into()
+/// }
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() {}
+/// ```
+pub struct TerminatingResult<T = (), E: error::Error = crate::Status> {
+    result: Result<T, TerminatingStatus<E>>,
+}
+
+impl<T, E: error::Error> From<Result<T, E>> for TerminatingResult<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        Self {
+            result: result.map_err(TerminatingStatus::from),
+        }
+    }
+}
+
+impl<T: fmt::Debug, E: error::Error> fmt::Debug for TerminatingResult<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.result {
+            Ok(value) => f.debug_tuple("Ok").field(value).finish(),
+            Err(error) => f.debug_tuple("Err").field(error).finish(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, E> std::process::Termination for TerminatingResult<T, E>
+where
+    T: std::process::Termination,
+    E: error::Error,
+    TerminatingStatus<E>: std::process::Termination,
+{
+    fn report(self) -> std::process::ExitCode {
+        match self.result {
+            Ok(value) => value.report(),
+            Err(error) => error.report(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+
+    use std::process::ExitCode;
+    use std::process::Termination;
+
+    #[derive(Copy, Clone, Debug, derive_more::Display)]
+    enum ErrorKind {
+        #[display(fmt = "Invalid argument")]
+        Usage,
+    }
+
+    impl crate::ExitCode for ErrorKind {
+        fn exit_code(&self) -> u8 {
+            crate::Sysexit::Usage.code()
+        }
+    }
+
+    #[test]
+    fn exit_code() {
+        let status = <crate::Status<ErrorKind>>::new(ErrorKind::Usage);
+        assert_eq!(TerminatingStatus::from(status).report(), ExitCode::from(64));
+
+        let status = <crate::Status>::new("Died");
+        assert_eq!(TerminatingStatus::from(status).report(), ExitCode::from(70));
+    }
+
+    #[test]
+    fn terminating_result() {
+        let result: Result<(), crate::Status<ErrorKind>> = Ok(());
+        assert_eq!(TerminatingResult::from(result).report(), ExitCode::SUCCESS);
+
+        let result: Result<(), _> =
+            Err(crate::Status::<ErrorKind>::new(ErrorKind::Usage).into_internal());
+        assert_eq!(TerminatingResult::from(result).report(), ExitCode::from(64));
+    }
+
+    #[test]
+    fn foreign_error() {
+        let error = std::io::Error::other("Died");
+        let status = TerminatingStatus::from(error);
+        assert!(format!("{:?}", status).starts_with("Died"));
+    }
+}
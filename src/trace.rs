@@ -0,0 +1,332 @@
+use std::fmt;
+
+use tracing::field;
+use tracing::Level;
+use tracing_error::SpanTrace;
+use tracing_error::SpanTraceStatus;
+
+use crate::Code;
+use crate::Context;
+use crate::InternalStatus;
+use crate::Kind;
+use crate::KindCode;
+use crate::Severity;
+use crate::Source;
+use crate::Status;
+
+impl<K: Kind, C: Context> Status<K, C> {
+    /// Emit an event for this status.
+    ///
+    /// The level follows the [`Severity`]: `ERROR` for [`Severity::Error`], `WARN` for
+    /// [`Severity::Warning`], and `INFO` for [`Severity::Note`].
+    ///
+    /// The event's fields are:
+    /// - `kind`: the `Display` of the [`Kind`].
+    /// - `severity`: the [`Severity`].
+    /// - `code`: the [`Code`], only with [`Status::record_with_code`].
+    /// - `context`: the [`Context`] as a JSON object, see [`Json`][crate::Json].
+    /// - `chain`: the public sources as a JSON array, see [`Json`][crate::Json].
+    /// - `related`: the public related errors as a JSON array of chains, when there are any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// fn read_file(path: &str) -> Result<String, status::Status> {
+    ///     std::fs::read_to_string(path).map_err(|e| {
+    ///         let status = <status::Status>::new("Failed to read file")
+    ///             .with_internal(e)
+    ///             .context_with(|c| c.insert("Path", path.to_owned()));
+    ///         status.record();
+    ///         status
+    ///     })
+    /// }
+    /// ```
+    pub fn record(&self) {
+        record(self, false, None)
+    }
+}
+
+impl<K: KindCode, C: Context> Status<K, C> {
+    /// Emit an event for this status, including its [`Code`].
+    ///
+    /// See [`Status::record`].
+    pub fn record_with_code(&self) {
+        record(self, false, Some(self.code()))
+    }
+}
+
+impl<K: Kind, C: Context> InternalStatus<K, C> {
+    /// Emit an event for this status, including private sources.
+    ///
+    /// See [`Status::record`].
+    pub fn record(&self) {
+        record(&self.0, true, None)
+    }
+
+    /// The span trace captured when the [`Status`] was created.
+    ///
+    /// This is only captured when the subscriber has a `tracing_error::ErrorLayer`.
+    pub fn span_trace(&self) -> Option<&SpanTrace> {
+        (self.0).inner.span_trace.as_ref()
+    }
+}
+
+impl<K: KindCode, C: Context> InternalStatus<K, C> {
+    /// Emit an event for this status, including private sources and its [`Code`].
+    ///
+    /// See [`Status::record`].
+    pub fn record_with_code(&self) {
+        record(&self.0, true, Some(self.0.code()))
+    }
+}
+
+pub(crate) fn capture_span_trace() -> Option<SpanTrace> {
+    let span_trace = SpanTrace::capture();
+    match span_trace.status() {
+        SpanTraceStatus::CAPTURED => Some(span_trace),
+        _ => None,
+    }
+}
+
+fn record<K: Kind, C: Context>(status: &Status<K, C>, internal: bool, code: Option<Code>) {
+    let inner = &status.inner;
+    let kind = inner.kind.to_string();
+    let kind = kind.trim_end();
+    let code = code.as_ref().map(field::display);
+    let related = crate::json::has_related(&inner.related, internal)
+        .then(|| field::display(RelatedField(&inner.related, internal)));
+    macro_rules! event {
+        ($level:expr) => {
+            tracing::event!(
+                $level,
+                kind,
+                severity = %inner.severity,
+                code,
+                context = %ContextField(&inner.data, internal),
+                chain = %ChainField(&inner.source, internal),
+                related,
+                "{}",
+                kind
+            )
+        };
+    }
+    match inner.severity {
+        Severity::Error => event!(Level::ERROR),
+        Severity::Warning => event!(Level::WARN),
+        Severity::Note => event!(Level::INFO),
+    }
+}
+
+struct ContextField<'a, C>(&'a C, bool);
+
+impl<C: Context> fmt::Display for ContextField<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::json::context(f, self.0, self.1)
+    }
+}
+
+struct ChainField<'a>(&'a Source, bool);
+
+impl fmt::Display for ChainField<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::json::chain(f, self.0, self.1)
+    }
+}
+
+struct RelatedField<'a>(&'a [Source], bool);
+
+impl fmt::Display for RelatedField<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::json::related(f, self.0, self.1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use tracing::field;
+    use tracing::span;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    type Fields = Arc<Mutex<Vec<(&'static str, String)>>>;
+    type Levels = Arc<Mutex<Vec<Level>>>;
+
+    struct Capture(Fields, Levels);
+
+    impl tracing::Subscriber for Capture {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            self.1.lock().unwrap().push(*event.metadata().level());
+            event.record(&mut Visitor(self.0.clone()));
+        }
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    struct Visitor(Fields);
+
+    impl field::Visit for Visitor {
+        fn record_debug(&mut self, field: &field::Field, value: &dyn fmt::Debug) {
+            let value = format!("{:?}", value);
+            self.0.lock().unwrap().push((field.name(), value));
+        }
+
+        fn record_str(&mut self, field: &field::Field, value: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name(), value.to_owned()));
+        }
+    }
+
+    fn field<'a>(fields: &'a [(&'static str, String)], name: &str) -> Option<&'a str> {
+        fields
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[test]
+    fn record() {
+        let fields = Fields::default();
+        let status = <Status>::new("Failed to read file")
+            .with_internal(io::Error::new(io::ErrorKind::NotFound, "No such file"))
+            .with_related(io::Error::other("Retried"))
+            .context_with(|c| c.insert("Path", "Cargo.toml").insert("Line", 3));
+        tracing::subscriber::with_default(Capture(fields.clone(), Levels::default()), || {
+            status.record();
+        });
+
+        let fields = fields.lock().unwrap();
+        let names: Vec<_> = fields.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            ["message", "kind", "severity", "context", "chain", "related"]
+        );
+        assert_eq!(field(&fields, "message"), Some("Failed to read file"));
+        assert_eq!(field(&fields, "kind"), Some("Failed to read file"));
+        assert_eq!(
+            field(&fields, "context"),
+            Some(r#"{"Path":"Cargo.toml","Line":"3"}"#)
+        );
+        assert_eq!(field(&fields, "chain"), Some("[]"));
+        assert!(field(&fields, "related")
+            .unwrap()
+            .contains(r#""message":"Retried""#));
+    }
+
+    #[test]
+    fn record_internal() {
+        let fields = Fields::default();
+        let status = <Status>::new("Failed to authenticate")
+            .with_internal(io::Error::new(io::ErrorKind::NotFound, "No such file"))
+            .context_with(|c| c.insert("Token", crate::Secret::new("hunter2")));
+        tracing::subscriber::with_default(Capture(fields.clone(), Levels::default()), || {
+            status.record();
+            status.into_internal().record();
+        });
+
+        let fields = fields.lock().unwrap();
+        let contexts: Vec<_> = fields
+            .iter()
+            .filter(|(name, _)| *name == "context")
+            .map(|(_, value)| value.as_str())
+            .collect();
+        assert_eq!(
+            contexts,
+            [r#"{"Token":"[redacted]"}"#, r#"{"Token":"hunter2"}"#]
+        );
+        let chains: Vec<_> = fields
+            .iter()
+            .filter(|(name, _)| *name == "chain")
+            .map(|(_, value)| value.as_str())
+            .collect();
+        assert_eq!(chains[0], "[]");
+        assert!(chains[1].contains(r#""message":"No such file","visibility":"private""#));
+    }
+
+    #[test]
+    fn record_with_code() {
+        #[derive(Copy, Clone, Debug, derive_more::Display)]
+        enum ErrorKind {
+            #[display(fmt = "Failed to read file")]
+            Read,
+        }
+
+        impl KindCode for ErrorKind {
+            fn code(&self) -> Code {
+                Code::from("io.read.E1042")
+            }
+
+            fn from_code(_code: Code) -> Option<Self> {
+                Some(Self::Read)
+            }
+        }
+
+        let fields = Fields::default();
+        let status = Status::<ErrorKind, crate::NoContext>::new(ErrorKind::Read);
+        tracing::subscriber::with_default(Capture(fields.clone(), Levels::default()), || {
+            status.record_with_code();
+        });
+
+        let fields = fields.lock().unwrap();
+        assert_eq!(field(&fields, "code"), Some("io.read.E1042"));
+    }
+
+    #[test]
+    fn level() {
+        let fields = Fields::default();
+        let levels = Levels::default();
+        tracing::subscriber::with_default(Capture(fields.clone(), levels.clone()), || {
+            for severity in [Severity::Error, Severity::Warning, Severity::Note] {
+                <Status>::new("Deprecated key")
+                    .with_severity(severity)
+                    .record();
+            }
+        });
+
+        assert_eq!(
+            *levels.lock().unwrap(),
+            [Level::ERROR, Level::WARN, Level::INFO]
+        );
+        let severities: Vec<_> = fields
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(name, _)| *name == "severity")
+            .map(|(_, value)| value.clone())
+            .collect();
+        assert_eq!(severities, ["error", "warning", "note"]);
+    }
+
+    #[test]
+    fn span_trace() {
+        let subscriber = tracing_subscriber::registry().with(tracing_error::ErrorLayer::default());
+        let status = tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::error_span!("read_file").entered();
+            <Status>::new("Failed to read file")
+        });
+
+        let status = status.into_internal();
+        assert!(status.span_trace().is_some());
+        assert!(status.to_string().contains("read_file"));
+    }
+}